@@ -1,6 +1,25 @@
 /* tslint:disable */
 /* eslint-disable */
+
 /**
-* @param {string} name
-*/
+ * Plain objects through serde-wasm-bindgen, e.g. `bounds([{ x: 0, y: 0 }, { x: 2, y: 1 }])`
+ * returns `{ min: { x: 0, y: 0 }, max: { x: 2, y: 1 }, pointCount: 2 }`.
+ */
+export function bounds(points: any): any;
+
+/**
+ * Takes a `Uint8Array`.
+ */
+export function checksum(bytes: Uint8Array): number;
+
 export function greet(name: string): void;
+
+/**
+ * Strings are passed in and out as UTF-8 copied through the linear memory.
+ */
+export function greeting(name: string): string;
+
+/**
+ * Takes a `Float64Array` and returns a new one.
+ */
+export function scale(values: Float64Array, factor: number): Float64Array;
@@ -1,2 +1,9 @@
+/* @ts-self-types="./index.d.ts" */
 import * as wasm from "./index_bg.wasm";
-export * from "./index_bg.js";
\ No newline at end of file
+import { __wbg_set_wasm } from "./index_bg.js";
+
+__wbg_set_wasm(wasm);
+wasm.__wbindgen_start();
+export {
+    bounds, checksum, greet, greeting, scale
+} from "./index_bg.js";
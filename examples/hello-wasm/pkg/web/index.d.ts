@@ -1,25 +1,66 @@
 /* tslint:disable */
 /* eslint-disable */
+
+/**
+ * Plain objects through serde-wasm-bindgen, e.g. `bounds([{ x: 0, y: 0 }, { x: 2, y: 1 }])`
+ * returns `{ min: { x: 0, y: 0 }, max: { x: 2, y: 1 }, pointCount: 2 }`.
+ */
+export function bounds(points: any): any;
+
 /**
-* @param {string} name
-*/
+ * Takes a `Uint8Array`.
+ */
+export function checksum(bytes: Uint8Array): number;
+
 export function greet(name: string): void;
 
+/**
+ * Strings are passed in and out as UTF-8 copied through the linear memory.
+ */
+export function greeting(name: string): string;
+
+/**
+ * Takes a `Float64Array` and returns a new one.
+ */
+export function scale(values: Float64Array, factor: number): Float64Array;
+
 export type InitInput = RequestInfo | URL | Response | BufferSource | WebAssembly.Module;
 
 export interface InitOutput {
-  readonly memory: WebAssembly.Memory;
-  readonly greet: (a: number, b: number) => void;
-  readonly __wbindgen_malloc: (a: number) => number;
-  readonly __wbindgen_realloc: (a: number, b: number, c: number) => number;
+    readonly memory: WebAssembly.Memory;
+    readonly bounds: (a: any) => [number, number, number];
+    readonly checksum: (a: number, b: number) => number;
+    readonly greet: (a: number, b: number) => void;
+    readonly greeting: (a: number, b: number) => [number, number];
+    readonly scale: (a: number, b: number, c: number) => [number, number];
+    readonly __wbindgen_malloc: (a: number, b: number) => number;
+    readonly __wbindgen_realloc: (a: number, b: number, c: number, d: number) => number;
+    readonly __wbindgen_exn_store: (a: number) => void;
+    readonly __externref_table_alloc: () => number;
+    readonly __wbindgen_externrefs: WebAssembly.Table;
+    readonly __externref_table_dealloc: (a: number) => void;
+    readonly __wbindgen_free: (a: number, b: number, c: number) => void;
+    readonly __wbindgen_start: () => void;
 }
 
+export type SyncInitInput = BufferSource | WebAssembly.Module;
+
+/**
+ * Instantiates the given `module`, which can either be bytes or
+ * a precompiled `WebAssembly.Module`.
+ *
+ * @param {{ module: SyncInitInput }} module - Passing `SyncInitInput` directly is deprecated.
+ *
+ * @returns {InitOutput}
+ */
+export function initSync(module: { module: SyncInitInput } | SyncInitInput): InitOutput;
+
 /**
-* If `module_or_path` is {RequestInfo} or {URL}, makes a request and
-* for everything else, calls `WebAssembly.instantiate` directly.
-*
-* @param {InitInput | Promise<InitInput>} module_or_path
-*
-* @returns {Promise<InitOutput>}
-*/
-export default function init (module_or_path?: InitInput | Promise<InitInput>): Promise<InitOutput>;
+ * If `module_or_path` is {RequestInfo} or {URL}, makes a request and
+ * for everything else, calls `WebAssembly.instantiate` directly.
+ *
+ * @param {{ module_or_path: InitInput | Promise<InitInput> }} module_or_path - Passing `InitInput` directly is deprecated.
+ *
+ * @returns {Promise<InitOutput>}
+ */
+export default function __wbg_init (module_or_path?: { module_or_path: InitInput | Promise<InitInput> } | InitInput | Promise<InitInput>): Promise<InitOutput>;
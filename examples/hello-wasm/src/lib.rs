@@ -1,4 +1,4 @@
-
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -8,5 +8,53 @@ extern "C" {
 
 #[wasm_bindgen]
 pub fn greet(name: &str) {
-    alert(&format!("Hello, {}!", name));
+    alert(&greeting(name));
+}
+
+/// Strings are passed in and out as UTF-8 copied through the linear memory.
+#[wasm_bindgen]
+pub fn greeting(name: &str) -> String {
+    format!("Hello, {}!", name)
+}
+
+/// Takes a `Uint8Array`.
+#[wasm_bindgen]
+pub fn checksum(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0u32, |sum, &byte| sum.wrapping_mul(31).wrapping_add(byte as u32))
+}
+
+/// Takes a `Float64Array` and returns a new one.
+#[wasm_bindgen]
+pub fn scale(values: &[f64], factor: f64) -> Vec<f64> {
+    values.iter().map(|value| value * factor).collect()
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bounds {
+    pub min: Point,
+    pub max: Point,
+    pub point_count: usize,
+}
+
+/// Plain objects through serde-wasm-bindgen, e.g. `bounds([{ x: 0, y: 0 }, { x: 2, y: 1 }])`
+/// returns `{ min: { x: 0, y: 0 }, max: { x: 2, y: 1 }, pointCount: 2 }`.
+#[wasm_bindgen]
+pub fn bounds(points: JsValue) -> Result<JsValue, JsValue> {
+    let points: Vec<Point> = serde_wasm_bindgen::from_value(points)?;
+    let first = *points.first().ok_or_else(|| JsValue::from_str("no points"))?;
+    let mut bounds = Bounds { min: first, max: first, point_count: points.len() };
+    for point in &points[1..] {
+        bounds.min.x = bounds.min.x.min(point.x);
+        bounds.min.y = bounds.min.y.min(point.y);
+        bounds.max.x = bounds.max.x.max(point.x);
+        bounds.max.y = bounds.max.y.max(point.y);
+    }
+    Ok(serde_wasm_bindgen::to_value(&bounds)?)
 }
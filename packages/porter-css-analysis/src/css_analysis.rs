@@ -0,0 +1,322 @@
+use std::path::Path;
+
+use serde::Serialize;
+
+/// `//` starts a line comment in LESS and SCSS but not in CSS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Syntax {
+    Css,
+    Less,
+    Scss,
+}
+
+impl Syntax {
+    /// By the extension of the file, `.sass` is not supported and treated as CSS.
+    pub fn from_filename(filename: &str) -> Syntax {
+        match Path::new(filename).extension().and_then(|ext| ext.to_str()) {
+            Some("less") => Syntax::Less,
+            Some("scss") => Syntax::Scss,
+            _ => Syntax::Css,
+        }
+    }
+}
+
+/// Byte offsets in the source, `end` is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CssImport {
+    pub url: String,
+    /// The url without quotes or `url()`.
+    pub span: Span,
+    /// The whole at-rule, from `@import` to the semicolon.
+    pub rule_span: Span,
+    /// `layer` is `Some("")`, `layer(base)` is `Some("base")`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supports: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub media: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CssUrl {
+    pub url: String,
+    /// The url without quotes or `url()`.
+    pub span: Span,
+}
+
+/// The dependencies of the stylesheet, in the order they appear.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CssAnalysis {
+    pub imports: Vec<CssImport>,
+    /// The `url()` references outside of `@import`.
+    pub urls: Vec<CssUrl>,
+}
+
+/// Finds the `@import` rules and `url()` references, skipping the ones in comments and
+/// strings:
+///
+/// ```css
+/// @import url("theme.css") layer(theme) supports(display: grid) screen and (min-width: 600px);
+/// .logo { background: url(./logo.png) }
+/// ```
+pub fn analyze_css(source: &str, syntax: Syntax) -> CssAnalysis {
+    let mut scanner = Scanner { src: source.as_bytes(), pos: 0, syntax };
+    let mut analysis = CssAnalysis::default();
+
+    while scanner.pos < scanner.src.len() {
+        if scanner.skip_comment() || scanner.skip_string().is_some() {
+            continue;
+        }
+        let start = scanner.pos;
+        if scanner.peek() == Some(b'@') {
+            scanner.pos += 1;
+            let name = scanner.ident();
+            if name.eq_ignore_ascii_case("import") {
+                analysis.imports.extend(scanner.import(source, start));
+            }
+            continue;
+        }
+        if scanner.is_ident_start_at(start) {
+            let name = scanner.ident();
+            if name.eq_ignore_ascii_case("url") && scanner.peek() == Some(b'(') {
+                if let Some((url, span)) = scanner.url(source) {
+                    analysis.urls.push(CssUrl { url, span });
+                }
+            }
+            continue;
+        }
+        scanner.pos += 1;
+    }
+    analysis
+}
+
+struct Scanner<'a> {
+    src: &'a [u8],
+    pos: usize,
+    syntax: Syntax,
+}
+
+fn is_ident_char(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'-' || c == b'_' || c >= 0x80
+}
+
+impl Scanner<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.src.get(self.pos).copied()
+    }
+
+    fn starts_with(&self, s: &str) -> bool {
+        self.src[self.pos..].starts_with(s.as_bytes())
+    }
+
+    fn is_ident_start_at(&self, pos: usize) -> bool {
+        is_ident_char(self.src[pos]) && (pos == 0 || !is_ident_char(self.src[pos - 1]))
+    }
+
+    fn ident(&mut self) -> &str {
+        let start = self.pos;
+        while self.peek().is_some_and(is_ident_char) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.src[start..self.pos]).unwrap_or_default()
+    }
+
+    fn skip_comment(&mut self) -> bool {
+        if self.starts_with("/*") {
+            self.pos = match self.src[self.pos + 2..].windows(2).position(|w| w == b"*/") {
+                Some(i) => self.pos + 2 + i + 2,
+                None => self.src.len(),
+            };
+            return true;
+        }
+        if self.syntax != Syntax::Css && self.starts_with("//") {
+            while self.peek().is_some_and(|c| c != b'\n') {
+                self.pos += 1;
+            }
+            return true;
+        }
+        false
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek().is_some_and(|c| c.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            self.skip_spaces();
+            if !self.skip_comment() {
+                break;
+            }
+        }
+    }
+
+    /// Skips the quoted string, and returns the span of the content.
+    fn skip_string(&mut self) -> Option<Span> {
+        let quote = self.peek().filter(|&c| c == b'"' || c == b'\'')?;
+        self.pos += 1;
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            match c {
+                b'\\' => self.pos += 2,
+                // unterminated string ends at the newline
+                b'\n' => break,
+                c if c == quote => {
+                    self.pos += 1;
+                    return Some(Span { start, end: self.pos - 1 });
+                }
+                _ => self.pos += 1,
+            }
+        }
+        self.pos = self.pos.min(self.src.len());
+        Some(Span { start, end: self.pos })
+    }
+
+    /// Skips the balanced parentheses, and returns the span of the content.
+    fn skip_parens(&mut self) -> Span {
+        let start = self.pos + 1;
+        let mut depth = 0;
+        while self.pos < self.src.len() {
+            if self.skip_comment() || self.skip_string().is_some() {
+                continue;
+            }
+            match self.src[self.pos] {
+                b'(' => depth += 1,
+                b')' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        self.pos += 1;
+                        return Span { start, end: self.pos - 1 };
+                    }
+                }
+                _ => {}
+            }
+            self.pos += 1;
+        }
+        Span { start, end: self.pos }
+    }
+
+    /// `url(foo.png)` or `url("foo.png")`, which is at the opening parenthesis.
+    fn url(&mut self, source: &str) -> Option<(String, Span)> {
+        self.pos += 1;
+        // no comments in url(), e.g. `url(//example.com/foo.png)`
+        self.skip_spaces();
+        let span = match self.skip_string() {
+            Some(span) => span,
+            None => {
+                let start = self.pos;
+                while self.peek().is_some_and(|c| c != b')') {
+                    self.pos += 1;
+                }
+                let end = start + source[start..self.pos].trim_end().len();
+                Span { start, end }
+            }
+        };
+        self.skip_spaces();
+        if self.peek() == Some(b')') {
+            self.pos += 1;
+        }
+        Some((source[span.start..span.end].to_string(), span)).filter(|(url, _)| !url.is_empty())
+    }
+
+    /// The import target, either a string or `url()`.
+    fn import_target(&mut self, source: &str) -> Option<(String, Span)> {
+        if let Some(span) = self.skip_string() {
+            return Some((source[span.start..span.end].to_string(), span));
+        }
+        let start = self.pos;
+        if self.ident().eq_ignore_ascii_case("url") && self.peek() == Some(b'(') {
+            return self.url(source);
+        }
+        self.pos = start;
+        None
+    }
+
+    /// The prelude of `@import` until the semicolon, which is right after the name.
+    fn import(&mut self, source: &str, rule_start: usize) -> Vec<CssImport> {
+        self.skip_whitespace();
+        // LESS options such as `@import (reference) "foo";`
+        if self.peek() == Some(b'(') {
+            self.skip_parens();
+            self.skip_whitespace();
+        }
+
+        let mut targets = Vec::new();
+        while let Some(target) = self.import_target(source) {
+            targets.push(target);
+            self.skip_whitespace();
+            // `@import "a", "b";` in LESS and SCSS
+            if self.peek() != Some(b',') {
+                break;
+            }
+            self.pos += 1;
+            self.skip_whitespace();
+        }
+
+        let mut layer = None;
+        let mut supports = None;
+        let start = self.pos;
+        let name = self.ident().to_ascii_lowercase();
+        match name.as_str() {
+            "layer" if self.peek() == Some(b'(') => {
+                let span = self.skip_parens();
+                layer = Some(trimmed(source, span));
+            }
+            "layer" => layer = Some(String::new()),
+            _ => self.pos = start,
+        }
+        self.skip_whitespace();
+        let start = self.pos;
+        if self.ident().eq_ignore_ascii_case("supports") && self.peek() == Some(b'(') {
+            let span = self.skip_parens();
+            supports = Some(trimmed(source, span));
+        } else {
+            self.pos = start;
+        }
+
+        let start = self.pos;
+        while self.pos < self.src.len() && !matches!(self.src[self.pos], b';' | b'{' | b'}') {
+            if self.skip_comment() || self.skip_string().is_some() {
+                continue;
+            }
+            if self.src[self.pos] == b'(' {
+                self.skip_parens();
+                continue;
+            }
+            self.pos += 1;
+        }
+        let media = trimmed(source, Span { start, end: self.pos });
+        if self.peek() == Some(b';') {
+            self.pos += 1;
+        }
+
+        let rule_span = Span { start: rule_start, end: self.pos };
+        targets
+            .into_iter()
+            .map(|(url, span)| CssImport {
+                url,
+                span,
+                rule_span,
+                layer: layer.clone(),
+                supports: supports.clone(),
+                media: Some(media.clone()).filter(|media| !media.is_empty()),
+            })
+            .collect()
+    }
+}
+
+fn trimmed(source: &str, span: Span) -> String {
+    source[span.start..span.end.min(source.len())].trim().to_string()
+}
@@ -0,0 +1,4 @@
+pub use self::css_analysis::*;
+pub mod css_analysis;
+#[cfg(target_arch = "wasm32")]
+mod wasm;
@@ -0,0 +1,48 @@
+//! The exports of the wasm binary, which passes the source and the result as UTF-8
+//! buffers allocated in the linear memory:
+//!
+//! ```js
+//! const ptr = exports.alloc(source.length);
+//! new Uint8Array(exports.memory.buffer, ptr, source.length).set(source);
+//! const result = exports.analyze_css_json(ptr, source.length, 0);
+//! const json = new Uint8Array(exports.memory.buffer, Number(result >> 32n), Number(result & 0xffffffffn));
+//! ```
+
+use crate::css_analysis::{analyze_css, Syntax};
+
+#[no_mangle]
+pub extern "C" fn alloc(len: usize) -> *mut u8 {
+    let mut buf = Vec::<u8>::with_capacity(len);
+    let ptr = buf.as_mut_ptr();
+    std::mem::forget(buf);
+    ptr
+}
+
+/// # Safety
+///
+/// The pointer must be the one returned by [alloc] or [analyze_css_json] with the same length.
+#[no_mangle]
+pub unsafe extern "C" fn dealloc(ptr: *mut u8, len: usize) {
+    drop(Vec::from_raw_parts(ptr, 0, len));
+}
+
+/// Takes the source and the syntax (0 for CSS, 1 for LESS, 2 for SCSS), and returns the
+/// pointer and the length of the JSON result packed in one u64, which is freed by [dealloc].
+///
+/// # Safety
+///
+/// The source must be a valid UTF-8 buffer allocated by [alloc].
+#[no_mangle]
+pub unsafe extern "C" fn analyze_css_json(ptr: *const u8, len: usize, syntax: u32) -> u64 {
+    let source = std::str::from_utf8_unchecked(std::slice::from_raw_parts(ptr, len));
+    let syntax = match syntax {
+        1 => Syntax::Less,
+        2 => Syntax::Scss,
+        _ => Syntax::Css,
+    };
+    let json = serde_json::to_vec(&analyze_css(source, syntax)).expect("failed to serialize css analysis");
+    let mut json = json.into_boxed_slice();
+    let (ptr, len) = (json.as_mut_ptr(), json.len());
+    std::mem::forget(json);
+    ((ptr as u64) << 32) | len as u64
+}
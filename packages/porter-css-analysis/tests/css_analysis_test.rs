@@ -0,0 +1,20 @@
+use std::{fs, path::PathBuf};
+
+use porter_css_analysis::{analyze_css, Syntax};
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fixture/**/input.*")]
+fn fixture(input: PathBuf) {
+    let source = fs::read_to_string(&input).unwrap();
+    let analysis = analyze_css(&source, Syntax::from_filename(input.to_str().unwrap()));
+    // the spans are checked against the source instead of being compared as numbers
+    for import in &analysis.imports {
+        assert_eq!(&source[import.span.start..import.span.end], import.url);
+        assert!(source[import.rule_span.start..import.rule_span.start + 7].eq_ignore_ascii_case("@import"));
+    }
+    for url in &analysis.urls {
+        assert_eq!(&source[url.span.start..url.span.end], url.url);
+    }
+    let json = serde_json::to_string_pretty(&analysis).unwrap();
+    NormalizedOutput::from(json).compare_to_file(input.parent().unwrap().join("output.json")).unwrap();
+}
@@ -0,0 +1,145 @@
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::ast::{
+        ArrowExpr, BindingIdent, BlockStmtOrExpr, CallExpr, Callee, Decl, Expr, ExprOrSpread, ExprStmt, Ident,
+        Import, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier,
+        Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, ModuleDecl, ModuleExportName, ModuleItem, NewExpr,
+        Pat, Stmt, Str, VarDecl, VarDeclKind, VarDeclarator,
+    },
+};
+
+pub fn ident(sym: &str) -> Ident {
+    Ident { span: DUMMY_SP, sym: sym.into(), optional: false }
+}
+
+pub fn ident_expr(sym: &str) -> Expr {
+    Expr::Ident(ident(sym))
+}
+
+pub fn str_lit(value: &str) -> Expr {
+    Expr::Lit(Lit::Str(Str::from(value)))
+}
+
+/// `obj.prop`
+pub fn member(obj: Expr, prop: &str) -> Expr {
+    Expr::Member(MemberExpr { span: DUMMY_SP, obj: Box::new(obj), prop: MemberProp::Ident(ident(prop)) })
+}
+
+/// `require.meta.url` from `["require", "meta", "url"]`, which has at least one name.
+pub fn member_chain(names: &[&str]) -> Expr {
+    let (first, rest) = names.split_first().expect("empty member chain");
+    rest.iter().fold(ident_expr(first), |obj, prop| member(obj, prop))
+}
+
+fn args(args: Vec<Expr>) -> Vec<ExprOrSpread> {
+    args.into_iter().map(|expr| ExprOrSpread { spread: None, expr: Box::new(expr) }).collect()
+}
+
+/// `callee(...args)`
+pub fn call(callee: Expr, args: Vec<Expr>) -> Expr {
+    Expr::Call(CallExpr { span: DUMMY_SP, callee: Callee::Expr(Box::new(callee)), args: self::args(args), type_args: None })
+}
+
+/// `new callee(...args)`
+pub fn new_expr(callee: Expr, args: Vec<Expr>) -> Expr {
+    Expr::New(NewExpr { span: DUMMY_SP, callee: Box::new(callee), args: Some(self::args(args)), type_args: None })
+}
+
+/// `(...params) => body`
+pub fn arrow(params: &[&str], body: Expr) -> Expr {
+    arrow_with(params, BlockStmtOrExpr::Expr(Box::new(body)))
+}
+
+/// The arrow function with a block body, e.g. `(result) => { ... }`.
+pub fn arrow_with(params: &[&str], body: BlockStmtOrExpr) -> Expr {
+    Expr::Arrow(ArrowExpr {
+        span: DUMMY_SP,
+        params: params.iter().map(|name| binding(ident(name))).collect(),
+        body: Box::new(body),
+        is_async: false,
+        is_generator: false,
+        type_params: None,
+        return_type: None,
+    })
+}
+
+pub fn binding(id: Ident) -> Pat {
+    Pat::Ident(BindingIdent { id, type_ann: None })
+}
+
+pub fn import_meta() -> Expr {
+    Expr::MetaProp(MetaPropExpr { span: DUMMY_SP, kind: MetaPropKind::ImportMeta })
+}
+
+/// `new URL("./foo.png", import.meta.url)`, which is how the assets are referenced, see
+/// `AssetVisitor` of swc-plugin-porter.
+pub fn new_url(specifier: &str) -> Expr {
+    new_expr(ident_expr("URL"), vec![str_lit(specifier), member(import_meta(), "url")])
+}
+
+/// `import("./foo")`
+pub fn dynamic_import(specifier: Expr) -> Expr {
+    Expr::Call(CallExpr {
+        span: DUMMY_SP,
+        callee: Callee::Import(Import { span: DUMMY_SP }),
+        args: args(vec![specifier]),
+        type_args: None,
+    })
+}
+
+/// `() => import("./foo")`, or `() => import("./foo").then((m) => m.bar)` if `export`
+/// is given.
+pub fn lazy_import(specifier: &str, export: Option<&str>) -> Expr {
+    let import = dynamic_import(str_lit(specifier));
+    let body = match export {
+        Some(export) => call(member(import, "then"), vec![arrow(&["m"], member(ident_expr("m"), export))]),
+        None => import,
+    };
+    arrow(&[], body)
+}
+
+pub fn default_specifier(local: Ident) -> ImportSpecifier {
+    ImportSpecifier::Default(ImportDefaultSpecifier { span: DUMMY_SP, local })
+}
+
+pub fn namespace_specifier(local: Ident) -> ImportSpecifier {
+    ImportSpecifier::Namespace(ImportStarAsSpecifier { span: DUMMY_SP, local })
+}
+
+/// `{ imported as local }`
+pub fn named_specifier(local: Ident, imported: &str) -> ImportSpecifier {
+    ImportSpecifier::Named(ImportNamedSpecifier {
+        span: DUMMY_SP,
+        local,
+        imported: Some(ModuleExportName::Ident(ident(imported))),
+        is_type_only: false,
+    })
+}
+
+/// `import ... from "./foo"`, or `import "./foo"` if there are no specifiers.
+pub fn import_decl(src: &str, specifiers: Vec<ImportSpecifier>) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
+        span: DUMMY_SP,
+        specifiers,
+        src: Box::new(Str::from(src)),
+        type_only: false,
+        asserts: None,
+    }))
+}
+
+/// `const a = 1, b;`
+pub fn var_decl(kind: VarDeclKind, decls: Vec<(Ident, Option<Expr>)>) -> Stmt {
+    Stmt::Decl(Decl::Var(Box::new(VarDecl {
+        span: DUMMY_SP,
+        kind,
+        declare: false,
+        decls: decls
+            .into_iter()
+            .map(|(id, init)| VarDeclarator { span: DUMMY_SP, name: binding(id), init: init.map(Box::new), definite: false })
+            .collect(),
+    })))
+}
+
+pub fn expr_stmt(expr: Expr) -> Stmt {
+    Stmt::Expr(ExprStmt { span: DUMMY_SP, expr: Box::new(expr) })
+}
@@ -0,0 +1,30 @@
+use serde::de::DeserializeOwned;
+use swc_core::plugin::{metadata::TransformPluginMetadataContextKind, proxies::TransformPluginProgramMetadata};
+
+/// Parses the plugin config, which falls back to the default if there is none. Invalid
+/// config panics, which fails the transform with the name of the plugin.
+pub fn parse_config<T>(json: Option<&str>, plugin: &str) -> T
+where
+    T: DeserializeOwned + Default,
+{
+    match json {
+        Some(json) => serde_json::from_str(json).unwrap_or_else(|err| panic!("invalid config for {plugin}: {err}")),
+        None => T::default(),
+    }
+}
+
+/// The config passed to the plugin, see [parse_config].
+pub fn plugin_config<T>(metadata: &TransformPluginProgramMetadata, plugin: &str) -> T
+where
+    T: DeserializeOwned + Default,
+{
+    parse_config(metadata.get_transform_plugin_config().as_deref(), plugin)
+}
+
+/// The path of the file being transformed, which is readable by the plugin because cwd
+/// is exposed to wasi at `/cwd`.
+pub fn plugin_filepath(metadata: &TransformPluginProgramMetadata) -> String {
+    let cwd = metadata.get_context(&TransformPluginMetadataContextKind::Cwd).expect("cwd required");
+    let filename = metadata.get_context(&TransformPluginMetadataContextKind::Filename).expect("filename required");
+    filename.replace(&cwd, "/cwd")
+}
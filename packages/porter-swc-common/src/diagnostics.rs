@@ -0,0 +1,12 @@
+use swc_core::common::{errors::HANDLER, Span};
+
+/// Reports the error to the host, which fails the transform once the plugin returns,
+/// hence the plugin should carry on and report the rest of the errors as well.
+pub fn error(span: Span, message: &str) {
+    HANDLER.with(|handler| handler.struct_span_err(span, message).emit());
+}
+
+/// Reports the warning to the host, which doesn't fail the transform.
+pub fn warning(span: Span, message: &str) {
+    HANDLER.with(|handler| handler.struct_span_warn(span, message).emit());
+}
@@ -0,0 +1,410 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// The files and directories matching the pattern, which is relative to `dir` unless it
+/// is absolute. The pattern syntax is the same as node-glob, which the TypeScript side
+/// of porter resolves `import.meta.glob()` with:
+///
+/// - `*`, `?`, `[a-z]`, `[!a-z]` within one path segment, and `**` across segments;
+/// - `{a,b}` and `{1..3}` brace expansion, which may be nested;
+/// - `?(a|b)`, `*(a|b)`, `+(a|b)`, `@(a|b)`, and `!(a|b)` extglobs;
+/// - the files and directories starting with `.` are matched only if the segment
+///   starts with `.` as well, e.g. `.*.js`.
+///
+/// The paths are joined onto `dir`, in the order of their components.
+pub fn glob(dir: &Path, pattern: &str) -> Vec<PathBuf> {
+    let mut paths = BTreeSet::new();
+    for pattern in expand_braces(pattern) {
+        let (root, pattern) = match pattern.strip_prefix('/') {
+            Some(rest) => (PathBuf::from("/"), rest.to_string()),
+            None => (dir.to_path_buf(), pattern),
+        };
+        let segments: Vec<Segment> = pattern.split('/').filter_map(Segment::parse).collect();
+        walk(root, &segments, &mut paths);
+    }
+    paths.into_iter().collect()
+}
+
+/// Escapes the characters that have special meanings in patterns, which matches the
+/// literal path as is.
+pub fn escape(literal: &str) -> String {
+    let mut escaped = String::with_capacity(literal.len());
+    for c in literal.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '{' | '}' | '(' | ')' | '|' | '!' | '+' | '@' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Expands `{a,b}` into `a` and `b`, and `{1..3}` or `{a..c}` into the sequence. Braces
+/// without commas or sequences are left as is, e.g. `{a}`.
+pub fn expand_braces(pattern: &str) -> Vec<String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut from = 0;
+    while let Some((open, close)) = find_braces(&chars, from) {
+        let body: String = chars[open + 1..close].iter().collect();
+        let alternatives = match split_commas(&body) {
+            alternatives if alternatives.len() > 1 => alternatives,
+            _ => sequence(&body).unwrap_or_default(),
+        };
+        if alternatives.is_empty() {
+            from = open + 1;
+            continue;
+        }
+        let pre: String = chars[..open].iter().collect();
+        let post: String = chars[close + 1..].iter().collect();
+        return alternatives
+            .iter()
+            .flat_map(|alternative| expand_braces(&format!("{pre}{alternative}{post}")))
+            .collect();
+    }
+    vec![pattern.to_string()]
+}
+
+/// The first pair of braces at or after `from`, skipping the escaped ones.
+fn find_braces(chars: &[char], from: usize) -> Option<(usize, usize)> {
+    let mut i = from;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1,
+            '{' => {
+                let mut depth = 0;
+                let mut j = i;
+                while j < chars.len() {
+                    match chars[j] {
+                        '\\' => j += 1,
+                        '{' => depth += 1,
+                        '}' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Some((i, j));
+                            }
+                        }
+                        _ => {}
+                    }
+                    j += 1;
+                }
+                return None;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Splits the body of braces at the commas that are not nested in other braces.
+fn split_commas(body: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    let mut depth = 0;
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                parts.last_mut().unwrap().push(c);
+                if let Some(c) = chars.next() {
+                    parts.last_mut().unwrap().push(c);
+                }
+                continue;
+            }
+            '{' => depth += 1,
+            '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(String::new());
+                continue;
+            }
+            _ => {}
+        }
+        parts.last_mut().unwrap().push(c);
+    }
+    parts
+}
+
+/// `1..3`, `01..10..3`, or `a..e`
+fn sequence(body: &str) -> Option<Vec<String>> {
+    let parts: Vec<&str> = body.split("..").collect();
+    let (start, end, step) = match parts.as_slice() {
+        [start, end] => (*start, *end, 1),
+        [start, end, step] => (*start, *end, step.parse::<i64>().ok()?.unsigned_abs().max(1)),
+        _ => return None,
+    };
+    if let (Ok(a), Ok(b)) = (start.parse::<i64>(), end.parse::<i64>()) {
+        // zero padded if either end is, e.g. `{01..10}`
+        let padded = |s: &str| s.trim_start_matches('-').len() > 1 && s.trim_start_matches('-').starts_with('0');
+        let width = if padded(start) || padded(end) { start.len().max(end.len()) } else { 0 };
+        let values: Vec<i64> = if a <= b {
+            (a..=b).step_by(step as usize).collect()
+        } else {
+            (b..=a).rev().step_by(step as usize).collect()
+        };
+        return Some(values.into_iter().map(|n| format!("{n:0width$}")).collect());
+    }
+    let (mut a, mut b) = (start.chars(), end.chars());
+    let (Some(a), None, Some(b), None) = (a.next(), a.next(), b.next(), b.next()) else { return None };
+    let values: Vec<u32> = if a <= b {
+        (a as u32..=b as u32).step_by(step as usize).collect()
+    } else {
+        (b as u32..=a as u32).rev().step_by(step as usize).collect()
+    };
+    Some(values.into_iter().filter_map(char::from_u32).map(String::from).collect())
+}
+
+enum Segment {
+    /// `**`
+    Globstar,
+    /// Segments without wildcards, which are looked up rather than listed.
+    Literal(String),
+    Pattern(Vec<Token>),
+}
+
+impl Segment {
+    fn parse(segment: &str) -> Option<Segment> {
+        match segment {
+            "" | "." => None,
+            "**" => Some(Segment::Globstar),
+            _ => {
+                let chars: Vec<char> = segment.chars().collect();
+                let tokens = tokenize(&chars);
+                let literal: Option<String> = tokens
+                    .iter()
+                    .map(|token| match token {
+                        Token::Char(c) => Some(*c),
+                        _ => None,
+                    })
+                    .collect();
+                Some(match literal {
+                    Some(name) => Segment::Literal(name),
+                    None => Segment::Pattern(tokens),
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExtKind {
+    /// `?(a|b)`
+    Optional,
+    /// `*(a|b)`
+    ZeroOrMore,
+    /// `+(a|b)`
+    OneOrMore,
+    /// `@(a|b)`
+    One,
+    /// `!(a|b)`
+    Not,
+}
+
+enum Token {
+    Char(char),
+    /// `?`
+    Any,
+    /// `*`
+    Star,
+    /// `[a-z]` or `[!a-z]`
+    Class { negated: bool, ranges: Vec<(char, char)> },
+    Ext(ExtKind, Vec<Vec<Token>>),
+}
+
+fn tokenize(chars: &[char]) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let kind = match c {
+            '?' => Some(ExtKind::Optional),
+            '*' => Some(ExtKind::ZeroOrMore),
+            '+' => Some(ExtKind::OneOrMore),
+            '@' => Some(ExtKind::One),
+            '!' => Some(ExtKind::Not),
+            _ => None,
+        };
+        if let Some(kind) = kind.filter(|_| chars.get(i + 1) == Some(&'(')) {
+            if let Some((alternatives, end)) = ext_alternatives(chars, i + 2) {
+                tokens.push(Token::Ext(kind, alternatives.iter().map(|chars| tokenize(chars)).collect()));
+                i = end + 1;
+                continue;
+            }
+        }
+        match c {
+            '\\' if i + 1 < chars.len() => {
+                tokens.push(Token::Char(chars[i + 1]));
+                i += 1;
+            }
+            '?' => tokens.push(Token::Any),
+            '*' if matches!(tokens.last(), Some(Token::Star)) => {}
+            '*' => tokens.push(Token::Star),
+            '[' => match class(chars, i + 1) {
+                Some((token, end)) => {
+                    tokens.push(token);
+                    i = end;
+                }
+                None => tokens.push(Token::Char(c)),
+            },
+            _ => tokens.push(Token::Char(c)),
+        }
+        i += 1;
+    }
+    tokens
+}
+
+/// The alternatives of the extglob that starts at `start`, and the position of the
+/// closing parenthesis.
+fn ext_alternatives(chars: &[char], start: usize) -> Option<(Vec<Vec<char>>, usize)> {
+    let mut alternatives = vec![Vec::new()];
+    let mut depth = 0;
+    let mut i = start;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if i + 1 < chars.len() => {
+                alternatives.last_mut().unwrap().extend([c, chars[i + 1]]);
+                i += 2;
+                continue;
+            }
+            '(' => depth += 1,
+            ')' if depth == 0 => return Some((alternatives, i)),
+            ')' => depth -= 1,
+            '|' if depth == 0 => {
+                alternatives.push(Vec::new());
+                i += 1;
+                continue;
+            }
+            _ => {}
+        }
+        alternatives.last_mut().unwrap().push(c);
+        i += 1;
+    }
+    None
+}
+
+/// The character class that starts at `start`, right after `[`, and the position of the
+/// closing bracket. A `]` right after the opening bracket is taken literally.
+fn class(chars: &[char], start: usize) -> Option<(Token, usize)> {
+    let mut i = start;
+    let negated = matches!(chars.get(i), Some('!' | '^'));
+    if negated {
+        i += 1;
+    }
+    let mut ranges = Vec::new();
+    let first = i;
+    while i < chars.len() {
+        let mut c = chars[i];
+        if c == ']' && i > first {
+            return Some((Token::Class { negated, ranges }, i));
+        }
+        if c == '\\' && i + 1 < chars.len() {
+            i += 1;
+            c = chars[i];
+        }
+        if chars.get(i + 1) == Some(&'-') && chars.get(i + 2).is_some_and(|&end| end != ']') {
+            ranges.push((c, chars[i + 2]));
+            i += 3;
+        } else {
+            ranges.push((c, c));
+            i += 1;
+        }
+    }
+    None
+}
+
+/// Whether the tokens match the whole text, which backtracks on the wildcards.
+fn matches(tokens: &[Token], text: &[char]) -> bool {
+    let Some((token, rest)) = tokens.split_first() else { return text.is_empty() };
+    match token {
+        Token::Char(c) => text.first() == Some(c) && matches(rest, &text[1..]),
+        Token::Any => !text.is_empty() && matches(rest, &text[1..]),
+        Token::Class { negated, ranges } => match text.first() {
+            Some(c) => ranges.iter().any(|(a, b)| (a..=b).contains(&c)) != *negated && matches(rest, &text[1..]),
+            None => false,
+        },
+        Token::Star => (0..=text.len()).any(|k| matches(rest, &text[k..])),
+        Token::Ext(kind, alternatives) => {
+            let one = |text: &[char]| alternatives.iter().any(|alternative| matches(alternative, text));
+            match kind {
+                ExtKind::One => (0..=text.len()).any(|k| one(&text[..k]) && matches(rest, &text[k..])),
+                ExtKind::Optional => {
+                    matches(rest, text) || (0..=text.len()).any(|k| one(&text[..k]) && matches(rest, &text[k..]))
+                }
+                ExtKind::ZeroOrMore => repeat(alternatives, rest, text),
+                ExtKind::OneOrMore => (0..=text.len()).any(|k| one(&text[..k]) && repeat(alternatives, rest, &text[k..])),
+                // anything but the alternatives followed by the rest, same as minimatch
+                ExtKind::Not => {
+                    !(0..=text.len()).any(|k| one(&text[..k]) && matches(rest, &text[k..]))
+                        && (0..=text.len()).any(|k| matches(rest, &text[k..]))
+                }
+            }
+        }
+    }
+}
+
+/// `*(a|b)` followed by the rest, each repetition consumes at least one character.
+fn repeat(alternatives: &[Vec<Token>], rest: &[Token], text: &[char]) -> bool {
+    matches(rest, text)
+        || (1..=text.len()).any(|k| {
+            alternatives.iter().any(|alternative| matches(alternative, &text[..k])) && repeat(alternatives, rest, &text[k..])
+        })
+}
+
+/// Whether the name of the file or directory matches the segment, the ones starting with
+/// `.` are matched by the segments starting with `.` only.
+fn matches_name(tokens: &[Token], name: &str) -> bool {
+    if name.starts_with('.') && !matches!(tokens.first(), Some(Token::Char('.'))) {
+        return false;
+    }
+    let chars: Vec<char> = name.chars().collect();
+    matches(tokens, &chars)
+}
+
+/// The entries of the directory with their names, skipping the ones that are not valid
+/// UTF-8. Fails silently as node-glob does, e.g. if `dir` is a file.
+fn entries(dir: &Path) -> Vec<(String, PathBuf, bool)> {
+    let Ok(entries) = fs::read_dir(dir) else { return Vec::new() };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            // symbolic links to directories are not followed by `**`, which avoids cycles
+            let is_dir = entry.file_type().is_ok_and(|file_type| file_type.is_dir());
+            Some((name, entry.path(), is_dir))
+        })
+        .collect()
+}
+
+fn walk(path: PathBuf, segments: &[Segment], paths: &mut BTreeSet<PathBuf>) {
+    let Some((segment, rest)) = segments.split_first() else {
+        if path.exists() {
+            paths.insert(path);
+        }
+        return;
+    };
+    match segment {
+        Segment::Literal(name) => walk(path.join(name), rest, paths),
+        Segment::Pattern(tokens) => {
+            for (name, entry, _) in entries(&path) {
+                if matches_name(tokens, &name) {
+                    walk(entry, rest, paths);
+                }
+            }
+        }
+        Segment::Globstar => {
+            walk(path.clone(), rest, paths);
+            for (name, entry, is_dir) in entries(&path) {
+                if name.starts_with('.') {
+                    continue;
+                }
+                if is_dir {
+                    walk(entry, segments, paths);
+                } else if rest.is_empty() {
+                    // the files under `foo/**`
+                    paths.insert(entry);
+                }
+            }
+        }
+    }
+}
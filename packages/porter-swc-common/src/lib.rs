@@ -0,0 +1,9 @@
+//! The building blocks shared by the swc plugins of porter, which are linked into each
+//! of the wasm binaries rather than being a plugin of its own.
+
+pub mod ast;
+pub mod config;
+pub mod diagnostics;
+pub mod glob;
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -0,0 +1,83 @@
+use std::{fs, path::Path};
+
+use swc_core::ecma::{
+    parser::Syntax,
+    transforms::{
+        base::pass::noop,
+        testing::{test_fixture as swc_test_fixture, FixtureTestConfig, Tester},
+    },
+    visit::Fold,
+};
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FixtureOptions {
+    pub syntax: Syntax,
+    /// Transforms the expected output once more, which should leave it as is. Transforms
+    /// that append to the module, e.g. the metadata of swc-plugin-porter, are not.
+    pub idempotent: bool,
+}
+
+/// Runs the fixture at `input`, which is compared with the `output` file of the same
+/// extension next to it. The optional files next to it are checked as well:
+///
+/// - `output.stderr` allows diagnostics, which are compared with it;
+/// - `output.map` is compared with the source map of the output.
+///
+/// Both of them are written with `UPDATE=1 cargo test` once created, e.g. with `touch`.
+pub fn test_fixture<P>(input: &Path, options: FixtureOptions, tr: &dyn Fn(&mut Tester) -> P)
+where
+    P: Fold,
+{
+    let dir = input.parent().unwrap();
+    let output = dir.join("output").with_extension(input.extension().unwrap_or_default());
+    let allow_error = output.with_extension("stderr").exists();
+    let sourcemap = output.with_extension("map").exists();
+
+    swc_test_fixture(options.syntax, tr, input, &output, FixtureTestConfig { allow_error, sourcemap });
+
+    if options.idempotent && !allow_error {
+        let expected = fs::read_to_string(&output).unwrap();
+        let (expected, actual) = Tester::run(|tester| {
+            let module = tester.apply_transform(noop(), "expected.js", options.syntax, &expected)?;
+            let expected = tester.print(&module, &tester.comments.clone());
+            let tr = tr(tester);
+            let module = tester.apply_transform(tr, "output.js", options.syntax, &expected)?;
+            Ok((expected, tester.print(&module, &tester.comments.clone())))
+        });
+        assert_eq!(expected, actual, "transforming {} again changes it", output.display());
+    }
+}
+
+/// Pins the signature of the transform closure of [fixture], which leaves the closure
+/// free of type annotations.
+pub fn transform<F, P>(tr: F) -> F
+where
+    F: Fn(&Path, &mut Tester) -> P,
+{
+    tr
+}
+
+/// Declares a test for each of the fixtures matching the pattern, see [test_fixture]. The
+/// transform is created with the path of the input and the tester:
+///
+/// ```ignore
+/// porter_swc_common::fixture!(fixture, "tests/fixture/**/input.js", |_input, t| {
+///     as_folder(deheredoc_transform(t.comments.clone()))
+/// });
+/// ```
+///
+/// [FixtureOptions] can be passed before the transform. The crate needs `testing` in its
+/// dev-dependencies.
+#[macro_export]
+macro_rules! fixture {
+    ($name:ident, $pattern:tt, $tr:expr) => {
+        $crate::fixture!($name, $pattern, ::std::default::Default::default(), $tr);
+    };
+    ($name:ident, $pattern:tt, $options:expr, $tr:expr) => {
+        #[testing::fixture($pattern)]
+        fn $name(input: ::std::path::PathBuf) {
+            let tr = $crate::testing::transform($tr);
+            $crate::testing::test_fixture(&input, $options, &|t| tr(input.as_path(), t));
+        }
+    };
+}
@@ -0,0 +1,60 @@
+use porter_swc_common::ast::{
+    call, expr_stmt, ident, import_decl, lazy_import, member_chain, named_specifier, namespace_specifier, new_url,
+    str_lit,
+};
+use swc_core::{
+    common::{sync::Lrc, SourceMap, DUMMY_SP},
+    ecma::{
+        ast::{Expr, Module, ModuleItem},
+        codegen::{text_writer::JsWriter, Emitter},
+    },
+};
+
+fn print(body: Vec<ModuleItem>) -> String {
+    let cm: Lrc<SourceMap> = Default::default();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: JsWriter::new(cm, "\n", &mut buf, None),
+        };
+        emitter.emit_module(&Module { span: DUMMY_SP, body, shebang: None }).unwrap();
+    }
+    String::from_utf8(buf).unwrap()
+}
+
+fn stmt(expr: Expr) -> ModuleItem {
+    ModuleItem::Stmt(expr_stmt(expr))
+}
+
+#[test]
+fn member_chain_and_call() {
+    let expr = call(member_chain(&["require", "async"]), vec![str_lit("./foo")]);
+    assert_eq!(print(vec![stmt(expr)]), "require.async(\"./foo\");\n");
+}
+
+#[test]
+fn import_decls() {
+    let body = vec![
+        import_decl("./foo.js", vec![namespace_specifier(ident("foo"))]),
+        import_decl("./bar.js", vec![named_specifier(ident("baz"), "bar")]),
+        import_decl("./baz.css", Vec::new()),
+    ];
+    assert_eq!(
+        print(body),
+        "import * as foo from \"./foo.js\";\nimport { bar as baz } from \"./bar.js\";\nimport \"./baz.css\";\n"
+    );
+}
+
+#[test]
+fn lazy_imports() {
+    let body = vec![stmt(lazy_import("./foo.js", None)), stmt(lazy_import("./bar.js", Some("default")))];
+    assert_eq!(print(body), "()=>import(\"./foo.js\");\n()=>import(\"./bar.js\").then((m)=>m.default);\n");
+}
+
+#[test]
+fn asset_urls() {
+    assert_eq!(print(vec![stmt(new_url("./foo.png"))]), "new URL(\"./foo.png\", import.meta.url);\n");
+}
@@ -0,0 +1,34 @@
+'use strict';
+
+const { transform, analyzeCss } = require('..');
+
+describe('transform', function() {
+  test('resolve imports and report assets', function() {
+    const code = [
+      "import React from 'react';",
+      "const worker = new Worker(new URL('./worker.js', import.meta.url));",
+      'console.log(React, worker);',
+    ].join('\n');
+    const result = transform(code, 'app.js', {
+      porter: { resolve: { react: 'react/18.2.0/index.js' } },
+    });
+    expect(result.code).toContain('from "react/18.2.0/index.js"');
+    expect(result.code).not.toContain('porterMetadata');
+    expect(result.metadata.imports).toEqual([
+      { source: 'react/18.2.0/index.js', names: [{ export: 'default', local: 'React' }] },
+    ]);
+    expect(result.metadata.assets).toEqual([{ specifier: './worker.js', kind: 'worker' }]);
+  });
+
+  test('throw on syntax error', function() {
+    expect(() => transform('const = 1;', 'broken.js')).toThrow();
+  });
+});
+
+describe('analyzeCss', function() {
+  test('scan @import and url()', function() {
+    const result = analyzeCss("@import './base.css';\na { background: url(./bg.png); }", 'app.css');
+    expect(result.imports.map(item => item.url)).toEqual(['./base.css']);
+    expect(result.urls.map(item => item.url)).toEqual(['./bg.png']);
+  });
+});
@@ -0,0 +1,38 @@
+export interface TransformOutput {
+  code: string;
+  map?: string;
+  /** `{ imports, dynamicImports, typeImports, __esModule, assets }` */
+  metadata: any;
+}
+
+/**
+ * Runs deheredoc, glob import and porter natively, which takes the same options as the
+ * config of swc_plugin_porter_preset.wasm, plus `sourceMaps` and `sourceFileName`.
+ */
+export function transform(code: string, filename: string, options?: Record<string, any>): TransformOutput;
+
+export interface Span {
+  start: number;
+  end: number;
+}
+
+export interface CssImport {
+  url: string;
+  span: Span;
+  ruleSpan: Span;
+  /** `''` for anonymous `layer` */
+  layer?: string;
+  supports?: string;
+  media?: string;
+}
+
+export interface CssAnalysis {
+  imports: CssImport[];
+  urls: { url: string; span: Span }[];
+}
+
+/**
+ * Scans the `@import` rules and `url()` references of CSS, LESS or SCSS, which is chosen by
+ * the extension of the filename. The spans are byte offsets of the UTF-8 code.
+ */
+export function analyzeCss(code: string, filename: string): CssAnalysis;
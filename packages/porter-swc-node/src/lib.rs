@@ -0,0 +1,41 @@
+use napi::{Error, Result, Status};
+use napi_derive::napi;
+use porter_css_analysis::{analyze_css, Syntax};
+use porter_swc::{transform, TransformOptions};
+
+#[napi(object)]
+pub struct JsTransformOutput {
+    pub code: String,
+    pub map: Option<String>,
+    /// `{ imports, dynamicImports, typeImports, __esModule, assets }`
+    pub metadata: serde_json::Value,
+}
+
+/// Runs deheredoc, glob import and porter natively, which takes the same options as the
+/// config of swc_plugin_porter_preset.wasm:
+///
+/// ```js
+/// const { transform } = require('@cara/porter-swc-node');
+/// const { code, map, metadata } = transform(code, fpath, { porter: { resolve } });
+/// ```
+#[napi(js_name = "transform")]
+pub fn transform_js(code: String, filename: String, options: Option<serde_json::Value>) -> Result<JsTransformOutput> {
+    let options: TransformOptions = match options {
+        Some(value) => serde_json::from_value(value)
+            .map_err(|err| Error::new(Status::InvalidArg, format!("invalid options: {err}")))?,
+        None => Default::default(),
+    };
+    let output = transform(code, &filename, options)
+        .map_err(|err| Error::new(Status::GenericFailure, err.to_string()))?;
+    let metadata = serde_json::to_value(&output.metadata)
+        .map_err(|err| Error::new(Status::GenericFailure, err.to_string()))?;
+    Ok(JsTransformOutput { code: output.code, map: output.map, metadata })
+}
+
+/// Scans the `@import` rules and `url()` references of CSS, LESS or SCSS, which is chosen by
+/// the extension of the filename.
+#[napi(js_name = "analyzeCss")]
+pub fn analyze_css_js(code: String, filename: String) -> Result<serde_json::Value> {
+    let analysis = analyze_css(&code, Syntax::from_filename(&filename));
+    serde_json::to_value(&analysis).map_err(|err| Error::new(Status::GenericFailure, err.to_string()))
+}
@@ -0,0 +1,95 @@
+use swc_core::{
+    common::{comments::SingleThreadedComments, sync::Lrc, BytePos, FileName, SourceFile, SourceMap, Span},
+    ecma::ast::Program,
+};
+use swc_plugin_deheredoc::codemod::deheredoc_codemod;
+use swc_plugin_glob_import::codemod::{glob_import_codemod, glob_import_revert};
+
+use crate::transform::{parse, TransformError};
+
+struct Source {
+    fm: Lrc<SourceFile>,
+    comments: SingleThreadedComments,
+    program: Program,
+}
+
+fn parse_source(code: &str, filename: &str) -> Result<Source, TransformError> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Real(filename.into()), code.to_string());
+    let comments = SingleThreadedComments::default();
+    let program = parse(&cm, &fm, filename, &comments)?;
+    Ok(Source { fm, comments, program })
+}
+
+/// Rewrites the heredoc calls in the code into template literals and removes the import
+/// of heredoc, see [deheredoc_codemod]. Returns `None` if there is nothing to rewrite, the
+/// code is left as is otherwise except for the replaced parts.
+pub fn deheredoc(code: &str, filename: &str) -> Result<Option<String>, TransformError> {
+    let Source { fm, comments, program } = parse_source(code, filename)?;
+    let replacements = deheredoc_codemod(&program, &comments);
+    if replacements.is_empty() {
+        return Ok(None);
+    }
+    Ok(Some(apply(code, fm.start_pos, replacements.into_iter().map(|r| (r.span, r.text)))))
+}
+
+/// Writes the files matched by `import.meta.glob()` into the code as static imports or
+/// maps of dynamic imports, see [glob_import_codemod]. The code generated previously is
+/// regenerated, and `None` is returned if it is up to date or there is no glob import.
+pub fn glob_import(code: &str, filename: &str) -> Result<Option<String>, TransformError> {
+    let Source { fm, comments, program } = parse_source(code, filename)?;
+    let replacements = glob_import_revert(&program, &comments);
+    let reverted = apply(code, fm.start_pos, replacements.into_iter().map(|r| (r.span, r.text)));
+
+    let Source { fm, program, .. } = parse_source(&reverted, filename)?;
+    let replacements = glob_import_codemod(&program, filename.to_string(), &fm);
+    let output = apply(&reverted, fm.start_pos, replacements.into_iter().map(|r| (r.span, r.text)));
+    Ok(if output == code { None } else { Some(output) })
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Expands the removed range to the whole line if nothing else is left on it, and to
+/// the blank line after if the removed line is preceded by a blank line as well.
+fn expand_removal(code: &str, lo: usize, hi: usize) -> (usize, usize) {
+    let line_start = code[..lo].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = code[hi..].find('\n').map_or(code.len(), |i| hi + i + 1);
+    if !is_blank(&code[line_start..lo]) || !is_blank(&code[hi..line_end]) {
+        return (lo, hi);
+    }
+    let prev_blank = line_start == 0 || {
+        let prev_start = code[..line_start - 1].rfind('\n').map_or(0, |i| i + 1);
+        is_blank(&code[prev_start..line_start])
+    };
+    let next_end = code[line_end..].find('\n').map_or(code.len(), |i| line_end + i + 1);
+    if prev_blank && line_end < code.len() && is_blank(&code[line_end..next_end]) {
+        (line_start, next_end)
+    } else {
+        (line_start, line_end)
+    }
+}
+
+/// Applies the replacements sorted by position, the ones overlapping with previous
+/// replacements are skipped.
+pub(crate) fn apply<I>(code: &str, start_pos: BytePos, replacements: I) -> String
+where
+    I: IntoIterator<Item = (Span, String)>,
+{
+    let mut result = String::with_capacity(code.len());
+    let mut pos = 0;
+    for (span, text) in replacements {
+        let lo = (span.lo - start_pos).0 as usize;
+        let hi = (span.hi - start_pos).0 as usize;
+        let (lo, hi) = if text.is_empty() { expand_removal(code, lo, hi) } else { (lo, hi) };
+        if lo < pos {
+            continue;
+        }
+        result.push_str(&code[pos..lo]);
+        result.push_str(&text);
+        pos = hi;
+    }
+    result.push_str(&code[pos..]);
+    result
+}
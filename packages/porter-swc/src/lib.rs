@@ -0,0 +1,3 @@
+pub use self::transform::*;
+pub mod codemod;
+pub mod transform;
@@ -0,0 +1,246 @@
+use std::{
+    collections::HashSet,
+    ffi::OsString,
+    fs,
+    path::{Component, Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Parser, ValueEnum};
+use glob::glob;
+use porter_swc::{codemod, transform, TransformError, TransformOptions};
+use similar::TextDiff;
+use swc_plugin_porter_preset::PorterOption;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Transform {
+    Deheredoc,
+    GlobImport,
+    Porter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Codemod {
+    Deheredoc,
+    GlobImport,
+}
+
+impl Codemod {
+    fn run(self, code: &str, filename: &str) -> Result<Option<String>, TransformError> {
+        match self {
+            Codemod::Deheredoc => codemod::deheredoc(code, filename),
+            Codemod::GlobImport => codemod::glob_import(code, filename),
+        }
+    }
+}
+
+/// Runs the transforms of porter over files, which prints the output or writes it
+/// into the out dir. With `--codemod` the files are rewritten in place instead.
+#[derive(Debug, Parser)]
+#[command(name = "porter-swc", version)]
+struct Cli {
+    /// Files or glob patterns such as `components/**/*.js`
+    #[arg(required = true)]
+    files: Vec<String>,
+
+    /// Transforms to apply, all of them if not specified
+    #[arg(short, long, value_enum, value_delimiter = ',')]
+    transform: Vec<Transform>,
+
+    /// JSON file with the options of swc-plugin-porter-preset
+    #[arg(short, long)]
+    config: Option<PathBuf>,
+
+    /// Rewrites the source files in place (or into --out-dir) and keeps the code that
+    /// isn't rewritten as is, e.g. `--codemod deheredoc` replaces heredoc with template
+    /// literals, and `--codemod glob-import` (re)generates the imports of import.meta.glob
+    #[arg(long, value_enum, conflicts_with_all = ["transform", "config", "source_maps"])]
+    codemod: Option<Codemod>,
+
+    /// Writes the output into the directory, keeping the paths relative to cwd
+    #[arg(short = 'd', long)]
+    out_dir: Option<PathBuf>,
+
+    /// Writes source maps next to the output files, requires --out-dir
+    #[arg(short, long, requires = "out_dir")]
+    source_maps: bool,
+
+    /// Compares the output with the files in --out-dir (or the source files with --codemod)
+    /// instead of writing them, exits with 1 if any of them differs
+    #[arg(long)]
+    check: bool,
+}
+
+impl Cli {
+    fn options(&self) -> Result<TransformOptions, String> {
+        let mut options: TransformOptions = match &self.config {
+            Some(path) => {
+                let json = fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))?;
+                serde_json::from_str(&json).map_err(|err| format!("{}: {err}", path.display()))?
+            }
+            None => Default::default(),
+        };
+        if !self.transform.is_empty() {
+            let preset = &mut options.preset;
+            preset.deheredoc = self.transform.contains(&Transform::Deheredoc);
+            preset.glob_import = self.transform.contains(&Transform::GlobImport);
+            if !self.transform.contains(&Transform::Porter) {
+                preset.porter = PorterOption::Enabled(false);
+            }
+        }
+        options.source_maps = self.source_maps;
+        Ok(options)
+    }
+
+    fn files(&self) -> Result<Vec<PathBuf>, String> {
+        let mut files = Vec::new();
+        for pattern in &self.files {
+            let path = Path::new(pattern);
+            if path.is_file() {
+                files.push(path.to_path_buf());
+                continue;
+            }
+            let paths = glob(pattern).map_err(|err| format!("{pattern}: {err}"))?;
+            let len = files.len();
+            files.extend(paths.flatten().filter(|path| path.is_file()));
+            if files.len() == len {
+                return Err(format!("{pattern}: no such file"));
+            }
+        }
+        // the same file might be matched by several patterns, the first match is kept
+        let mut seen = HashSet::new();
+        files.retain(|file| seen.insert(file.clone()));
+        Ok(files)
+    }
+}
+
+/// `./foo/bar.js` => `<out_dir>/foo/bar.js`, files outside of cwd are written at the
+/// root of out dir.
+fn output_path(out_dir: &Path, file: &Path) -> PathBuf {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let relative = match file.strip_prefix(&cwd) {
+        Ok(relative) => relative,
+        Err(_) if file.is_relative() && !file.starts_with("..") => file,
+        Err(_) => Path::new(file.file_name().unwrap()),
+    };
+    out_dir.join(relative.strip_prefix("./").unwrap_or(relative))
+}
+
+/// Relative path from the directory to the file, both of which are resolved against cwd.
+fn relative_path(dir: &Path, file: &Path) -> PathBuf {
+    let cwd = std::env::current_dir().unwrap_or_default();
+    let normalize = |path: &Path| -> Vec<OsString> {
+        let mut result = Vec::new();
+        for component in cwd.join(path).components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => { result.pop(); }
+                _ => result.push(component.as_os_str().to_owned()),
+            }
+        }
+        result
+    };
+    let from = normalize(dir);
+    let to = normalize(file);
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut result = PathBuf::new();
+    for _ in common..from.len() {
+        result.push("..");
+    }
+    result.extend(&to[common..]);
+    result
+}
+
+fn run(cli: &Cli) -> Result<bool, String> {
+    if cli.check && cli.out_dir.is_none() && cli.codemod.is_none() {
+        return Err("--check requires --out-dir or --codemod".into());
+    }
+    let options = cli.options()?;
+    let files = cli.files()?;
+    let mut ok = true;
+
+    for file in &files {
+        let code = fs::read_to_string(file).map_err(|err| format!("{}: {err}", file.display()))?;
+        let target = cli.out_dir.as_ref().map(|out_dir| output_path(out_dir, file));
+
+        if let Some(codemod) = cli.codemod {
+            let output = match codemod.run(&code, &file.to_string_lossy()) {
+                Ok(output) => output,
+                Err(err) => {
+                    eprintln!("error: {err}");
+                    ok = false;
+                    continue;
+                }
+            };
+            // untouched files are left as is, or copied into out dir byte by byte
+            let output = output.unwrap_or(code);
+            ok &= write_output(cli, &target.unwrap_or_else(|| file.clone()), output)?;
+            continue;
+        }
+
+        let mut options = options.clone();
+        // `sources` in source map are relative to the map file
+        if let Some(target) = &target {
+            let source = relative_path(target.parent().unwrap(), file);
+            options.source_file_name = Some(source.to_string_lossy().into_owned());
+        }
+        let output = match transform(code, &file.to_string_lossy(), options) {
+            Ok(output) => output,
+            Err(err) => {
+                eprintln!("error: {err}");
+                ok = false;
+                continue;
+            }
+        };
+
+        let Some(target) = target else {
+            if files.len() > 1 { println!("// {}", file.display()); }
+            print!("{}", output.code);
+            continue;
+        };
+
+        let mut code = output.code;
+        if let Some(map) = &output.map {
+            let name = target.file_name().unwrap().to_string_lossy();
+            code.push_str(&format!("//# sourceMappingURL={name}.map\n"));
+            ok &= write_output(cli, &target.with_file_name(format!("{name}.map")), map.clone())?;
+        }
+
+        ok &= write_output(cli, &target, code)?;
+    }
+
+    Ok(ok)
+}
+
+/// Writes the code into the target unless it is the same already, or prints the diff
+/// and returns false if it differs with --check.
+fn write_output(cli: &Cli, target: &Path, code: String) -> Result<bool, String> {
+    let existing = fs::read_to_string(target).ok();
+    if existing.as_ref() == Some(&code) {
+        return Ok(true);
+    }
+    if cli.check {
+        let existing = existing.unwrap_or_default();
+        let diff = TextDiff::from_lines(&existing, &code);
+        let header = target.display().to_string();
+        print!("{}", diff.unified_diff().header(&header, &header));
+        return Ok(false);
+    }
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+    }
+    fs::write(target, code).map_err(|err| format!("{}: {err}", target.display()))?;
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match run(&cli) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
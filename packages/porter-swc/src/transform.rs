@@ -0,0 +1,194 @@
+use std::{
+    fmt,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+use swc_core::{
+    common::{
+        comments::{Comments, SingleThreadedComments},
+        errors::{DiagnosticBuilder, Emitter as DiagnosticEmitter, Handler, HANDLER},
+        sync::Lrc,
+        FileName, Mark, SourceFile, SourceMap, Span, Spanned, GLOBALS,
+    },
+    ecma::{
+        ast::{EsVersion, Program},
+        codegen::{text_writer::JsWriter, Emitter},
+        parser::{lexer::Lexer, EsConfig, Parser, StringInput, Syntax, TsConfig},
+        transforms::base::resolver,
+        visit::VisitMutWith,
+    },
+};
+use swc_plugin_import_analysis::{analyze_imports, type_import_elision, ImportAnalysis};
+use swc_plugin_porter::AssetReference;
+use swc_plugin_porter_preset::{porter_preset, Config};
+
+/// Same as the config of swc-plugin-porter-preset, plus the options of the output.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TransformOptions {
+    #[serde(flatten)]
+    pub preset: Config,
+
+    /// Generates the source map of the transformed code, which is on by default.
+    pub source_maps: bool,
+
+    /// The `sources` in source map, defaults to the filename.
+    pub source_file_name: Option<String>,
+}
+
+impl Default for TransformOptions {
+    fn default() -> Self {
+        TransformOptions { preset: Default::default(), source_maps: true, source_file_name: None }
+    }
+}
+
+/// Imports of the transformed code, which replaces the metadata comments emitted by the
+/// wasm plugins.
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformMetadata {
+    #[serde(flatten)]
+    pub imports: ImportAnalysis,
+    pub assets: Vec<AssetReference>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransformOutput {
+    pub code: String,
+    pub map: Option<String>,
+    pub metadata: TransformMetadata,
+}
+
+/// Syntax errors with the location where the parser failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransformError {
+    pub filename: String,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for TransformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}:{}:{})", self.message, self.filename, self.line, self.column)
+    }
+}
+
+impl std::error::Error for TransformError {}
+
+fn syntax(filename: &str) -> Syntax {
+    match Path::new(filename).extension().and_then(|ext| ext.to_str()) {
+        Some("ts" | "mts" | "cts") => Syntax::Typescript(TsConfig { decorators: true, ..Default::default() }),
+        Some("tsx") => Syntax::Typescript(TsConfig { tsx: true, decorators: true, ..Default::default() }),
+        _ => Syntax::Es(EsConfig { jsx: true, decorators: true, ..Default::default() }),
+    }
+}
+
+/// Keeps the errors reported by the transforms through [HANDLER], e.g. the missing
+/// classes of CSS modules.
+#[derive(Clone, Default)]
+struct Diagnostics(Arc<Mutex<Vec<(Span, String)>>>);
+
+impl DiagnosticEmitter for Diagnostics {
+    fn emit(&mut self, db: &DiagnosticBuilder<'_>) {
+        if db.is_error() {
+            let span = db.span.primary_span().unwrap_or_default();
+            self.0.lock().unwrap().push((span, db.message()));
+        }
+    }
+}
+
+fn error_at(cm: &SourceMap, filename: &str, span: Span, message: String) -> TransformError {
+    let loc = cm.lookup_char_pos(span.lo);
+    TransformError { filename: filename.to_string(), line: loc.line, column: loc.col_display + 1, message }
+}
+
+/// Parses the source file, the first of the recoverable errors fails it as well.
+pub(crate) fn parse(
+    cm: &SourceMap,
+    fm: &SourceFile,
+    filename: &str,
+    comments: &SingleThreadedComments,
+) -> Result<Program, TransformError> {
+    let error = |span: Span, message: String| error_at(cm, filename, span, message);
+
+    let lexer = Lexer::new(syntax(filename), EsVersion::latest(), StringInput::from(fm), Some(comments));
+    let mut parser = Parser::new_from(lexer);
+    let program = parser.parse_program().map_err(|err| error(err.span(), err.kind().msg().into()))?;
+    if let Some(err) = parser.take_errors().into_iter().next() {
+        return Err(error(err.span(), err.kind().msg().into()));
+    }
+    Ok(program)
+}
+
+/// Parses the code, runs the transforms of porter, and prints it back with source map.
+/// The output is meant to be passed on to `@swc/core` without any plugins.
+pub fn transform(code: String, filename: &str, options: TransformOptions) -> Result<TransformOutput, TransformError> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(FileName::Real(filename.into()), code);
+    let comments = SingleThreadedComments::default();
+    let syntax = syntax(filename);
+    let mut program = parse(&cm, &fm, filename, &comments)?;
+
+    let globals = Default::default();
+    let mut preset = porter_preset(filename.to_string(), options.preset, comments.clone());
+    let diagnostics = Diagnostics::default();
+    let handler = Handler::with_emitter(true, false, Box::new(diagnostics.clone()));
+    GLOBALS.set(&globals, || {
+        program.visit_mut_with(&mut resolver(Mark::new(), Mark::new(), syntax.typescript()));
+        HANDLER.set(&handler, || program.visit_mut_with(&mut preset));
+    });
+    // the first error fails the transform, same as the syntax errors
+    if let Some((span, message)) = diagnostics.0.lock().unwrap().first().cloned() {
+        return Err(error_at(&cm, filename, span, message));
+    }
+    // the metadata is returned as is, no need to keep the comment
+    let hi = program.span().hi;
+    if let Some(trailing) = comments.take_trailing(hi) {
+        let trailing = trailing.into_iter().filter(|c| !c.text.starts_with("# porterMetadata=")).collect();
+        comments.add_trailing_comments(hi, trailing);
+    }
+
+    let mut metadata = TransformMetadata {
+        imports: match &program {
+            Program::Module(module) => analyze_imports(module),
+            Program::Script(script) => analyze_imports(script),
+        },
+        assets: preset.metadata().assets.clone(),
+    };
+    metadata.imports.es_module |= preset.metadata().es_module;
+    // the printer doesn't keep `import type`, which have to be dropped instead
+    if syntax.typescript() {
+        GLOBALS.set(&globals, || program.visit_mut_with(&mut type_import_elision()));
+    }
+
+    let mut buf = Vec::new();
+    let mut srcmap = Vec::new();
+    {
+        let wr = JsWriter::new(cm.clone(), "\n", &mut buf, options.source_maps.then_some(&mut srcmap));
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: Some(&comments),
+            wr,
+        };
+        emitter.emit_program(&program).expect("failed to print program");
+    }
+    let code = String::from_utf8(buf).expect("invalid utf8 in generated code");
+
+    let map = if options.source_maps {
+        let mut map = cm.build_source_map(&srcmap);
+        let source_file_name = options.source_file_name.as_deref().unwrap_or(filename);
+        map.set_source(0, source_file_name);
+        map.set_source_contents(0, Some(&fm.src));
+        let mut json = Vec::new();
+        map.to_writer(&mut json).expect("failed to write source map");
+        Some(String::from_utf8(json).expect("invalid utf8 in source map"))
+    } else {
+        None
+    };
+
+    Ok(TransformOutput { code, map, metadata })
+}
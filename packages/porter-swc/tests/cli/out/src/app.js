@@ -0,0 +1,4 @@
+import React from "react/18.2.0/index.js";
+const template = '<div id="app"></div>';
+console.log(React, template, require.meta.url);
+//# sourceMappingURL=app.js.map
@@ -0,0 +1,7 @@
+import heredoc from 'heredoc';
+import React from 'react';
+
+const template = heredoc(function() {/*
+  <div id="app"></div>
+*/});
+console.log(React, template, import.meta.url);
@@ -0,0 +1,3 @@
+import React from 'react';
+const template = '<div id="app"></div>';
+console.log(React, template, import.meta.url);
@@ -0,0 +1,124 @@
+use std::{
+    env, fs,
+    path::Path,
+    process::{Command, Output},
+};
+
+use testing::NormalizedOutput;
+
+fn porter_swc(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_porter-swc"))
+        .current_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/cli"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn print_chosen_transforms() {
+    let output = porter_swc(&["src/*.js", "--transform", "deheredoc"]);
+    assert!(output.status.success());
+    NormalizedOutput::from(String::from_utf8(output.stdout).unwrap())
+        .compare_to_file("tests/cli/stdout.js")
+        .unwrap();
+}
+
+#[test]
+fn print_duplicates_once() {
+    let output = porter_swc(&["src/app.js", "src/*.js", "src/app.js", "--transform", "deheredoc"]);
+    assert!(output.status.success());
+    NormalizedOutput::from(String::from_utf8(output.stdout).unwrap())
+        .compare_to_file("tests/cli/stdout.js")
+        .unwrap();
+}
+
+#[test]
+fn check_out_dir() {
+    // UPDATE=1 writes the expected output instead
+    if env::var("UPDATE").is_ok() {
+        let output = porter_swc(&["src/app.js", "--config", "config.json", "--out-dir", "out", "--source-maps"]);
+        assert!(output.status.success());
+    }
+    let output = porter_swc(&["src/app.js", "--config", "config.json", "--out-dir", "out", "--source-maps", "--check"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+}
+
+#[test]
+fn check_stale_output() {
+    let out_dir = env::temp_dir().join(format!("porter-swc-check-{}", std::process::id()));
+    fs::create_dir_all(out_dir.join("src")).unwrap();
+    fs::write(out_dir.join("src/app.js"), "console.log('stale');\n").unwrap();
+
+    let output = porter_swc(&["src/app.js", "--out-dir", out_dir.to_str().unwrap(), "--check"]);
+    fs::remove_dir_all(&out_dir).unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let diff = String::from_utf8(output.stdout).unwrap();
+    assert!(diff.contains("-console.log('stale');"), "{diff}");
+    assert!(diff.contains("+const template = '<div id=\"app\"></div>';"), "{diff}");
+}
+
+#[test]
+fn check_stale_map() {
+    let out_dir = env::temp_dir().join(format!("porter-swc-check-map-{}", std::process::id()));
+    fs::create_dir_all(out_dir.join("src")).unwrap();
+    let expected = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/cli/out/src");
+    fs::copy(expected.join("app.js"), out_dir.join("src/app.js")).unwrap();
+    fs::write(out_dir.join("src/app.js.map"), "{}").unwrap();
+
+    let args = ["src/app.js", "--config", "config.json", "--out-dir", out_dir.to_str().unwrap(), "--source-maps", "--check"];
+    let output = porter_swc(&args);
+    let map = fs::read_to_string(out_dir.join("src/app.js.map")).unwrap();
+    fs::remove_dir_all(&out_dir).unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let diff = String::from_utf8(output.stdout).unwrap();
+    assert!(diff.contains("app.js.map"), "{diff}");
+    // the code itself is up to date
+    assert!(!diff.contains("app.js\n"), "{diff}");
+    // --check leaves the stale map as is
+    assert_eq!(map, "{}");
+}
+
+#[test]
+fn missing_files() {
+    let output = porter_swc(&["missing/*.js"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8(output.stderr).unwrap(), "error: missing/*.js: no such file\n");
+}
+
+#[test]
+fn codemod_in_place() {
+    let dir = env::temp_dir().join(format!("porter-swc-codemod-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let app = dir.join("app.js");
+    let untouched = dir.join("untouched.js");
+    fs::copy(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/cli/src/app.js"), &app).unwrap();
+    fs::write(&untouched, "const  text = `template`  ;\n").unwrap();
+    let files = [app.to_str().unwrap(), untouched.to_str().unwrap()];
+
+    let check = porter_swc(&[&files[..], &["--codemod", "deheredoc", "--check"]].concat());
+    assert_eq!(check.status.code(), Some(1));
+    let diff = String::from_utf8(check.stdout).unwrap();
+    assert!(diff.contains("-import heredoc from 'heredoc';"), "{diff}");
+    assert!(!diff.contains("untouched.js"), "{diff}");
+
+    let output = porter_swc(&[&files[..], &["--codemod", "deheredoc"]].concat());
+    assert!(output.status.success());
+    let code = fs::read_to_string(&app).unwrap();
+    let untouched_code = fs::read_to_string(&untouched).unwrap();
+    let check = porter_swc(&[&files[..], &["--codemod", "deheredoc", "--check"]].concat());
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(check.status.success());
+    assert_eq!(untouched_code, "const  text = `template`  ;\n");
+    assert_eq!(code, "import React from 'react';\n\nconst template = `<div id=\"app\"></div>`;\nconsole.log(React, template, import.meta.url);\n");
+}
+
+#[test]
+fn codemod_check_drift() {
+    let output = porter_swc(&["../codemod/glob-import/stale/output.js", "--codemod", "glob-import", "--check"]);
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+
+    let output = porter_swc(&["../codemod/glob-import/stale/input.js", "--codemod", "glob-import", "--check"]);
+    assert_eq!(output.status.code(), Some(1));
+    let diff = String::from_utf8(output.stdout).unwrap();
+    assert!(diff.contains("+import * as __glob_0_1 from '../data/b.json';"), "{diff}");
+}
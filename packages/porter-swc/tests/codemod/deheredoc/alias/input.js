@@ -0,0 +1,6 @@
+const hd = require('heredoc'), heredoc = require('heredoc');
+import heredocToo from 'heredoc';
+
+// only the calls of heredoc are rewritten, hence hd is kept
+const a = heredoc(function() {/* rewritten */});
+const b = hd(function() {/* left as is */});
@@ -0,0 +1,5 @@
+const hd = require('heredoc');
+
+// only the calls of heredoc are rewritten, hence hd is kept
+const a = `rewritten`;
+const b = hd(function() {/* left as is */});
@@ -0,0 +1,7 @@
+const path = require('path'), heredoc = require('heredoc'), fs = require('fs');
+const a = 1,
+  heredoc2 = require('heredoc');
+
+module.exports = heredoc(function() {/*
+  declarators
+*/});
@@ -0,0 +1,4 @@
+const path = require('path'), fs = require('fs');
+const a = 1;
+
+module.exports = `declarators`;
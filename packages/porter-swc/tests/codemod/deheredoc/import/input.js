@@ -0,0 +1,13 @@
+import heredoc from 'heredoc';
+import { render } from './render';
+
+/**
+ * Renders the greeting.
+ */
+export function greet(name) {
+  return render(heredoc(function() {/*
+    <p>
+      hakuna matata
+    </p>
+  */}), { name });
+}
@@ -0,0 +1,10 @@
+import { render } from './render';
+
+/**
+ * Renders the greeting.
+ */
+export function greet(name) {
+  return render(`<p>
+  hakuna matata
+</p>`, { name });
+}
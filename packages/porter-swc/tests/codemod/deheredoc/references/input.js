@@ -0,0 +1,5 @@
+const heredoc = require('heredoc');
+
+const text = heredoc(function() {/* converted */});
+// heredoc is still referenced, hence the require is kept
+exports.heredoc = heredoc;
@@ -0,0 +1,26 @@
+'use strict';
+
+var heredoc = require('heredoc');
+
+// the template of app
+var bar = heredoc(function() {/*
+  <!doctype html>
+  <html>
+    <head>
+      <style>
+        body { font: ${size}px sans-serif; }
+      </style>
+    </head>
+    <body class=`app`></body>
+  </html>
+*/}) /* trailing */ + footer;
+
+test('deheredoc', function() {
+  expect(heredoc(function() {/* foobar */})).toEqual('foobar');
+});
+
+var baz = heredoc(() => {/*
+  arrow function with \n
+*/})
+
+console.log(heredoc(() => {/* heredoc as argument */}))
@@ -0,0 +1,20 @@
+'use strict';
+
+// the template of app
+var bar = `<!doctype html>
+<html>
+  <head>
+    <style>
+      body { font: \${size}px sans-serif; }
+    </style>
+  </head>
+  <body class=\`app\`></body>
+</html>` /* trailing */ + footer;
+
+test('deheredoc', function() {
+  expect(`foobar`).toEqual('foobar');
+});
+
+var baz = `arrow function with \\n`
+
+console.log(`heredoc as argument`)
@@ -0,0 +1,3 @@
+// heredoc isn't used here,   spacing   and comments are kept
+const  text = `template`  ;
+console.log( text )
@@ -0,0 +1,13 @@
+import React from 'react'; // the only import
+
+/* eslint-disable no-console */
+export const locales = import.meta.glob('../data/*.json', { eager: true });
+
+function load() {
+  const all = import.meta.glob(
+    '../data/**/*.json',
+    { eager: true },
+  );
+  return all;
+}
+console.log(React, locales, load());
@@ -0,0 +1,25 @@
+import React from 'react'; // the only import
+// @generated by `porter-swc --codemod glob-import`
+import * as __glob_0_0 from '../data/a.json';
+import * as __glob_0_1 from '../data/b.json';
+import * as __glob_1_0 from '../data/a.json';
+import * as __glob_1_1 from '../data/b.json';
+import * as __glob_1_2 from '../data/nested/c.json';
+
+/* eslint-disable no-console */
+// @generated by `porter-swc --codemod glob-import` from import.meta.glob('../data/*.json', { eager: true })
+export const locales = {
+  '../data/a.json': __glob_0_0,
+  '../data/b.json': __glob_0_1
+};
+
+function load() {
+  // @generated by `porter-swc --codemod glob-import` from import.meta.glob('../data/**/*.json', { eager: true })
+  const all = {
+    '../data/a.json': __glob_1_0,
+    '../data/b.json': __glob_1_1,
+    '../data/nested/c.json': __glob_1_2
+  };
+  return all;
+}
+console.log(React, locales, load());
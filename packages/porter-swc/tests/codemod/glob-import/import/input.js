@@ -0,0 +1,2 @@
+const names = import.meta.glob('../data/*.json', { eager: true, import: 'name' });
+const lazy = import.meta.glob('../data/*.json', { import: 'name' });
@@ -0,0 +1,14 @@
+// @generated by `porter-swc --codemod glob-import`
+import { name as __glob_0_0 } from '../data/a.json';
+import { name as __glob_0_1 } from '../data/b.json';
+
+// @generated by `porter-swc --codemod glob-import` from import.meta.glob('../data/*.json', { eager: true, import: 'name' })
+const names = {
+  '../data/a.json': __glob_0_0,
+  '../data/b.json': __glob_0_1
+};
+// @generated by `porter-swc --codemod glob-import` from import.meta.glob('../data/*.json', { import: 'name' })
+const lazy = {
+  '../data/a.json': () => import('../data/a.json').then((m) => m.name),
+  '../data/b.json': () => import('../data/b.json').then((m) => m.name)
+};
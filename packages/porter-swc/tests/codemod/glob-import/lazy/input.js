@@ -0,0 +1,5 @@
+// lazy maps of the data
+let data;
+data = import.meta.glob("../data/**/*.json");
+const none = import.meta.glob("../data/*.txt");
+console.log(data, none);
@@ -0,0 +1,11 @@
+// lazy maps of the data
+let data;
+// @generated by `porter-swc --codemod glob-import` from import.meta.glob("../data/**/*.json")
+data = {
+  "../data/a.json": () => import("../data/a.json"),
+  "../data/b.json": () => import("../data/b.json"),
+  "../data/nested/c.json": () => import("../data/nested/c.json")
+};
+// @generated by `porter-swc --codemod glob-import` from import.meta.glob("../data/*.txt")
+const none = {};
+console.log(data, none);
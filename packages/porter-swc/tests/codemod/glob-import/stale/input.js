@@ -0,0 +1,8 @@
+// @generated by `porter-swc --codemod glob-import`
+import __glob_0_0 from '../data/a.json';
+
+// @generated by `porter-swc --codemod glob-import` from import.meta.glob('../data/*.json', { eager: true })
+const locales = {
+  '../data/a.json': __glob_0_0
+};
+console.log(locales);
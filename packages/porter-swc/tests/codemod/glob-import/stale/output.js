@@ -0,0 +1,10 @@
+// @generated by `porter-swc --codemod glob-import`
+import * as __glob_0_0 from '../data/a.json';
+import * as __glob_0_1 from '../data/b.json';
+
+// @generated by `porter-swc --codemod glob-import` from import.meta.glob('../data/*.json', { eager: true })
+const locales = {
+  '../data/a.json': __glob_0_0,
+  '../data/b.json': __glob_0_1
+};
+console.log(locales);
@@ -0,0 +1,3 @@
+const urls = import.meta.glob('../data/*.json', { eager: true, as: 'url' });
+const lazy = import.meta.glob('../data/*.json', { as: 'url' });
+console.log(urls, lazy);
@@ -0,0 +1,11 @@
+// @generated by `porter-swc --codemod glob-import` from import.meta.glob('../data/*.json', { eager: true, as: 'url' })
+const urls = {
+  '../data/a.json': new URL('../data/a.json', import.meta.url).href,
+  '../data/b.json': new URL('../data/b.json', import.meta.url).href
+};
+// @generated by `porter-swc --codemod glob-import` from import.meta.glob('../data/*.json', { as: 'url' })
+const lazy = {
+  '../data/a.json': () => Promise.resolve(new URL('../data/a.json', import.meta.url).href),
+  '../data/b.json': () => Promise.resolve(new URL('../data/b.json', import.meta.url).href)
+};
+console.log(urls, lazy);
@@ -0,0 +1,26 @@
+use std::{fs, path::PathBuf};
+
+use porter_swc::codemod;
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/codemod/deheredoc/**/input.js")]
+fn deheredoc(input: PathBuf) {
+    let code = fs::read_to_string(&input).unwrap();
+    let output = codemod::deheredoc(&code, input.to_str().unwrap()).unwrap();
+    let output_path = input.parent().unwrap().join("output.js");
+    match output {
+        Some(output) => NormalizedOutput::from(output).compare_to_file(output_path).unwrap(),
+        // untouched files have no output
+        None => assert!(!output_path.exists(), "{} is not rewritten", input.display()),
+    }
+}
+
+#[testing::fixture("tests/codemod/glob-import/**/input.js")]
+fn glob_import(input: PathBuf) {
+    let code = fs::read_to_string(&input).unwrap();
+    let filename = input.to_str().unwrap();
+    let output = codemod::glob_import(&code, filename).unwrap().unwrap();
+    // regenerates nothing if up to date
+    assert_eq!(codemod::glob_import(&output, filename).unwrap(), None);
+    NormalizedOutput::from(output).compare_to_file(input.parent().unwrap().join("output.js")).unwrap();
+}
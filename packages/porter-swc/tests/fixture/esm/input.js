@@ -0,0 +1,10 @@
+import heredoc from 'heredoc';
+import React from 'react';
+import { render } from 'react-dom';
+
+const template = heredoc(function() {/*
+  <div id="app"></div>
+*/});
+const worker = new Worker(new URL('./worker.js', import.meta.url));
+import('./lazy').then(exports => console.log(exports));
+render(React.createElement('div', null, template, worker), document.body);
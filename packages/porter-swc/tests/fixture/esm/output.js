@@ -0,0 +1,6 @@
+import React from "react/18.2.0/index.js";
+import { render } from "react-dom/18.2.0/index.js";
+const template = '<div id="app"></div>';
+const worker = new Worker(new URL('./worker.js', require.meta.url));
+import("./lazy.js").then((exports)=>console.log(exports));
+render(React.createElement('div', null, template, worker), document.body);
@@ -0,0 +1,7 @@
+import type { Options } from './options';
+import { Button, type ButtonProps } from './button';
+
+// keeps comments
+export function create(options: Options): ButtonProps {
+  return new Button(options);
+}
@@ -0,0 +1,5 @@
+import { Button } from './button';
+// keeps comments
+export function create(options: Options): ButtonProps {
+    return new Button(options);
+}
@@ -0,0 +1,37 @@
+// Runs the glue of examples/hello-wasm transformed by porter-swc in place of a browser,
+// with alert(), fetch() and the `require.meta` provided by porter's loader stubbed out.
+// Prints the results as JSON, which are checked by hello_wasm_test.rs.
+import { readFile } from 'node:fs/promises';
+import { fileURLToPath, pathToFileURL } from 'node:url';
+
+const [entry] = process.argv.slice(2);
+const url = pathToFileURL(entry).href;
+const alerts = [];
+
+globalThis.alert = message => alerts.push(message);
+globalThis.fetch = async input => {
+  const body = await readFile(fileURLToPath(input));
+  return new Response(body, { headers: { 'content-type': 'application/wasm' } });
+};
+// the modules are in the same directory as the entry
+globalThis.require = { meta: { url } };
+
+const hello = await import(url);
+hello.greet('porter');
+
+let error;
+try {
+  hello.bounds([]);
+} catch (err) {
+  error = String(err);
+}
+
+const scaled = hello.scale(new Float64Array([1, 2.5]), 2);
+console.log(JSON.stringify({
+  alerts,
+  greeting: hello.greeting('世界'),
+  checksum: hello.checksum(new Uint8Array([1, 2, 3])),
+  scaled: { type: scaled.constructor.name, values: Array.from(scaled) },
+  bounds: hello.bounds([{ x: 0, y: 0 }, { x: 2, y: 1 }, { x: -1, y: 3 }]),
+  error,
+}, null, 2));
@@ -0,0 +1,85 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use porter_swc::{transform, TransformOptions};
+use serde_json::json;
+
+fn succeeds(command: &mut Command) -> bool {
+    command.output().is_ok_and(|output| output.status.success())
+}
+
+/// The toolchain needed to build examples/hello-wasm, which is not around by default.
+fn check_toolchain() {
+    let output = Command::new("rustc").args(["--print", "target-libdir", "--target", "wasm32-unknown-unknown"]).output();
+    let libdir = output.map(|output| PathBuf::from(String::from_utf8_lossy(&output.stdout).trim())).unwrap_or_default();
+    assert!(libdir.is_dir(), "requires `rustup target add wasm32-unknown-unknown`");
+    assert!(succeeds(Command::new("wasm-bindgen").arg("--version")), "requires `cargo install wasm-bindgen-cli`");
+    assert!(succeeds(Command::new("node").arg("--version")), "requires node");
+}
+
+/// Builds examples/hello-wasm with wasm-bindgen, transforms the glue with the `.wasm`
+/// imports instantiated and the `.wasm` specifier rewritten with a hard-coded `manifest`
+/// option, then runs it with node in place of a browser.
+///
+/// Run with `cargo test -p porter-swc --test hello_wasm_test -- --ignored`.
+#[test]
+#[ignore = "requires the wasm32-unknown-unknown target, wasm-bindgen-cli and node"]
+fn hello_wasm() {
+    check_toolchain();
+    let example = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../examples/hello-wasm");
+    let status = Command::new("cargo")
+        .current_dir(&example)
+        .args(["build", "--release", "--target", "wasm32-unknown-unknown"])
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let dir = env::temp_dir().join(format!("porter-swc-hello-wasm-{}", std::process::id()));
+    let pkg = dir.join("pkg");
+    let status = Command::new("wasm-bindgen")
+        .args(["--target", "bundler", "--out-name", "index", "--omit-imports", "--out-dir"])
+        .arg(&pkg)
+        .arg(example.join("target/wasm32-unknown-unknown/release/hello_world.wasm"))
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let options: TransformOptions = serde_json::from_value(json!({
+        "porter": {
+            "wasmImport": "topLevelAwait",
+            "manifest": { "./index_bg.wasm": "./index_bg.5d41402a.wasm" },
+        },
+        "sourceMaps": false,
+    }))
+    .unwrap();
+    for entry in fs::read_dir(&pkg).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|ext| ext == "js") {
+            let code = fs::read_to_string(&path).unwrap();
+            let output = transform(code, path.to_str().unwrap(), options.clone()).unwrap();
+            assert!(!output.code.contains("\"./index_bg.wasm\""), "{}", output.code);
+            fs::write(&path, output.code).unwrap();
+        }
+    }
+    // the imports of the wasm file are read while transforming
+    fs::rename(pkg.join("index_bg.wasm"), pkg.join("index_bg.5d41402a.wasm")).unwrap();
+    fs::write(pkg.join("package.json"), r#"{ "type": "module" }"#).unwrap();
+
+    let stub = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/hello-wasm/stub.mjs");
+    let output = Command::new("node").arg(stub).arg(pkg.join("index.js")).output().unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let result: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(result, json!({
+        "alerts": ["Hello, porter!"],
+        "greeting": "Hello, 世界!",
+        "checksum": 1026,
+        "scaled": { "type": "Float64Array", "values": [2, 5] },
+        "bounds": { "min": { "x": -1, "y": 0 }, "max": { "x": 2, "y": 3 }, "pointCount": 3 },
+        "error": "no points",
+    }));
+}
@@ -0,0 +1,33 @@
+use std::{fs, path::PathBuf};
+
+use porter_swc::{transform, TransformOptions};
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fixture/**/input.*")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let code = fs::read_to_string(&input).unwrap();
+    let options_path = dir.join("options.json");
+    let mut options: TransformOptions = match fs::read_to_string(options_path) {
+        Ok(json) => serde_json::from_str(&json).unwrap(),
+        Err(_) => Default::default(),
+    };
+    // keeps the source map free of absolute paths
+    options.source_file_name = Some(input.file_name().unwrap().to_str().unwrap().to_string());
+
+    match transform(code, input.to_str().unwrap(), options) {
+        Ok(output) => {
+            let ext = input.extension().unwrap().to_str().unwrap();
+            NormalizedOutput::from(output.code).compare_to_file(dir.join(format!("output.{ext}"))).unwrap();
+            let metadata = serde_json::to_string_pretty(&output.metadata).unwrap();
+            NormalizedOutput::from(metadata).compare_to_file(dir.join("output.json")).unwrap();
+            let map: serde_json::Value = serde_json::from_str(&output.map.unwrap()).unwrap();
+            let map = serde_json::to_string_pretty(&map).unwrap();
+            NormalizedOutput::from(map).compare_to_file(dir.join("output.map")).unwrap();
+        }
+        Err(err) => {
+            let message = err.to_string().replace(dir.to_str().unwrap(), "$DIR");
+            NormalizedOutput::from(message).compare_to_file(dir.join("output.stderr")).unwrap();
+        }
+    }
+}
@@ -6,14 +6,39 @@ import Module, { TranspileOptions } from './module';
 import JsonModule from './json_module';
 import { MODULE_LOADING, MODULE_LOADED } from './constants';
 
-// FIXME
-// switch to swc css or lightning css perhaps?
+// the fallback of analyzeCss(), which misses the `@import`s after comments or in one line
 const rAtImport = /(?:^|\n)\s*@import\s+(?:(['"])([^'"]+)\1|url\((['"])?([^\)]+)\3?\))[ ;]/g;
 
+interface CssAnalysis {
+  analyzeCss(code: string, filename: string): { imports: { url: string }[] };
+}
+
+let cssAnalysis: CssAnalysis | null | undefined;
+
+/**
+ * analyzeCss() of porter-css-analysis, which is exposed by the native @cara/porter-swc-node
+ */
+function loadCssAnalysis(): CssAnalysis | null {
+  if (cssAnalysis === undefined) {
+    try {
+      cssAnalysis = require('@cara/porter-swc-node');
+    } catch {
+      cssAnalysis = null;
+    }
+  }
+  return cssAnalysis;
+}
+
 export default class CssModule extends Module {
   exports?: JsonModule;
 
   matchImport(code: string) {
+    const analysis = this.app.swc === true ? loadCssAnalysis() : null;
+    if (analysis) {
+      this.imports = analysis.analyzeCss(code, this.fpath).imports.map(({ url }) => url);
+      return;
+    }
+
     const imports: string[] = [];
     let m;
 
@@ -19,20 +19,47 @@ import Visitor from '@swc/core/Visitor';
 
 interface ImportName { export: string, local: string }
 
-interface Import {
+/**
+ * The options of `import.meta.glob()` that decide which of the matched files are inlined
+ * by swc-plugin-glob-import rather than imported.
+ */
+export interface GlobOptions {
+  /** the size limit of `{ inline: 4096 }`, `{ inline: true }` has no limit */
+  inline?: number;
+  /** `{ import: 'foo' }`, the key is always inlined from the JSON files of eager globs */
+  import?: string;
+}
+
+/**
+ * The regexp of `require.context()`, which the files matched by the pattern are tested
+ * against with their paths relative to the directory, e.g. `./a.svg`.
+ */
+export interface ContextRegExp {
+  source: string;
+  flags: string;
+}
+
+export interface Import extends GlobOptions {
   source: string;
   names?: ImportName[];
   pattern?: string;
+  regexp?: ContextRegExp;
 }
 
-interface DynamicImport {
+export interface DynamicImport extends GlobOptions {
   source: string;
   pattern?: string;
+  regexp?: ContextRegExp;
 }
 
-interface GlobImport {
-  pattern: string;
-  eager: boolean;
+/**
+ * The result of ImportVisitor, which is the same as the one reported by swc_plugin_import_analysis.wasm
+ */
+export interface ImportAnalysis {
+  imports: Import[];
+  dynamicImports: DynamicImport[];
+  typeImports: string[];
+  __esModule: boolean;
 }
 
 const equalOperators = ['==', '===', '!=', '!=='];
@@ -54,6 +81,7 @@ function globImport(args: Argument[]) {
   if (pattern?.type !== 'StringLiteral') return;
   const options = args.length > 1 && args[1].expression;
   let eager = false;
+  const glob: GlobOptions = {};
   if (options && options.type === 'ObjectExpression') {
     for (const prop of options.properties) {
       if (prop.type !== 'KeyValueProperty') continue;
@@ -61,12 +89,49 @@ function globImport(args: Argument[]) {
       if (prop.key.value === 'eager' && prop.value.type === 'BooleanLiteral') {
         eager = prop.value.value;
       }
+      // assets rather than imports
+      if (prop.key.value === 'as' && prop.value.type === 'StringLiteral' && prop.value.value === 'url') {
+        return;
+      }
+      if (prop.key.value === 'inline') {
+        if (prop.value.type === 'BooleanLiteral' && prop.value.value) glob.inline = Infinity;
+        if (prop.value.type === 'NumericLiteral') glob.inline = prop.value.value;
+      }
+      if (prop.key.value === 'import' && prop.value.type === 'StringLiteral') {
+        glob.import = prop.value.value;
+      }
     }
   }
-  return { pattern: pattern.value, eager };
+  return { pattern: pattern.value, eager, glob };
+}
+
+/**
+ * `require.context('./icons', true, /\.svg$/)`, the defaults are the same as webpack's,
+ * the ones that swc-plugin-glob-import can't transform are skipped.
+ */
+function requireContext(args: Argument[]) {
+  const [directory, recursive, regexp, mode] = args.map(arg => arg.expression);
+  if (directory?.type !== 'StringLiteral') return;
+  let deep = true;
+  if (recursive) {
+    if (recursive.type !== 'BooleanLiteral') return;
+    deep = recursive.value;
+  }
+  let context: ContextRegExp = { source: '^\\./.*$', flags: '' };
+  if (regexp) {
+    if (regexp.type !== 'RegExpLiteral') return;
+    context = { source: regexp.pattern, flags: regexp.flags };
+  }
+  let lazy = false;
+  if (mode) {
+    if (mode.type !== 'StringLiteral' || !['sync', 'lazy'].includes(mode.value)) return;
+    lazy = mode.value === 'lazy';
+  }
+  const source = directory.value.replace(/\/+$/, '');
+  return { source, pattern: deep ? `${source}/**/*` : `${source}/*`, regexp: context, lazy };
 }
 
-export default class ImportVisitor extends Visitor {
+export default class ImportVisitor extends Visitor implements ImportAnalysis {
   imports: Import[] = [];
   dynamicImports: DynamicImport[] = [];
   typeImports: string[] = [];
@@ -154,15 +219,27 @@ export default class ImportVisitor extends Visitor {
         this.dynamicImports.push({ source: expr.value });
         return node;
       }
+      // require.context('./icons', true, /\.svg$/)
+      if (object.type === 'Identifier' && object.value === 'require' && property.type === 'Identifier' && property.value === 'context') {
+        const result = requireContext(node.arguments);
+        if (!result) return node;
+        const { lazy, ...context } = result;
+        if (lazy) {
+          this.dynamicImports.push(context);
+        } else {
+          this.imports.push(context);
+        }
+        return node;
+      }
       // import.meta.glob('./data/*.json')
       if (object.type === 'MetaProperty' && property.type === 'Identifier' && property.value === 'glob') {
         const result = globImport(node.arguments);
         if (!result) return node;
-        const { pattern, eager } = result;
+        const { pattern, eager, glob } = result;
         if (eager) {
-          this.imports.push({ source: pattern, pattern });
+          this.imports.push({ source: pattern, pattern, ...glob });
         } else {
-          this.dynamicImports.push({ source: pattern, pattern });
+          this.dynamicImports.push({ source: pattern, pattern, ...glob });
         }
       }
     }
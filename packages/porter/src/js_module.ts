@@ -2,15 +2,16 @@ import Debug from 'debug';
 import path from 'path';
 import UglifyJS from 'uglify-js';
 import fs from 'fs/promises';
+import { readFileSync, statSync } from 'fs';
 import merge from 'lodash/merge';
-import { transform, parseSync, Program } from '@swc/core';
+import { transform, transformSync, parseSync, Program } from '@swc/core';
 
-import Module, { ModuleCache, SourceOptions, TranspileOptions } from './module';
+import Module, { Asset, ModuleCache, SourceOptions, TranspileOptions } from './module';
 import * as namedImport from './named_import';
 
 import { MODULE_LOADING, MODULE_LOADED } from './constants';
 import { RawSourceMap } from 'source-map';
-import ImportVisitor from './import_visitor';
+import ImportVisitor, { GlobOptions, Import, ImportAnalysis } from './import_visitor';
 import { glob } from 'glob';
 
 const debug = Debug('porter');
@@ -20,37 +21,77 @@ interface Stream {
   pipe: (stream: Stream) => Stream;
 }
 
-type Plugins = [string, Record<string, any>][];
+function resolvePlugin(name: string): string {
+  try {
+    return require.resolve(`../${name}`)
+  } catch {
+    return require.resolve(`../../../target/wasm32-wasi/debug/${name}`);
+  }
+}
 
-function loadPlugins(): Plugins {
-  const plugins = [
-    'swc_plugin_deheredoc.wasm',
-    'swc_plugin_glob_import.wasm',
-    'swc_plugin_porter.wasm',
-  ];
-  return plugins.map(name => {
-    let fpath = '';
-    try {
-      fpath = require.resolve(`../${name}`)
-    } catch {
-      fpath = require.resolve(`../../../target/wasm32-wasi/debug/${name}`);
-    }
-    return [fpath, { displayName: true }]
-  });
+// deheredoc, glob import, and porter chained in one plugin
+let presetPlugin: string;
+let importAnalysisPlugin: string;
+
+interface Metadata {
+  assets?: Asset[];
+  __esModule?: boolean;
 }
 
-let plugins: Plugins;
+/**
+ * The swc plugins report what they found in trailing comments like `//# porterMetadata={...}`,
+ * which are removed from the code.
+ */
+function extractComment<T>(code: string, key: string): { code: string, value?: T } {
+  const match = code.match(new RegExp(`\\n?//# ${key}=(.*)\\s*$`));
+  if (!match) return { code };
+  return { code: code.slice(0, match.index), value: JSON.parse(match[1]) };
+}
+
+// the files that are modules rather than data, which are never inlined
+const moduleExtensions = [
+  '.js', '.jsx', '.mjs', '.cjs', '.ts', '.tsx', '.mts', '.cts', '.css', '.less', '.sass', '.scss', '.wasm',
+];
+
+/**
+ * Whether the file matched by `import.meta.glob()` is inlined by swc-plugin-glob-import,
+ * which is the same check as the plugin, hence the file is no longer a dependency.
+ */
+function isInlined(fpath: string, options: GlobOptions, eager: boolean) {
+  const { inline, import: name } = options;
+  if (name && name !== 'default') {
+    return (eager || inline != null) && fpath.endsWith('.json');
+  }
+  if (inline == null || moduleExtensions.includes(path.extname(fpath))) return false;
+  if (statSync(fpath).size > inline) return false;
+  if (fpath.endsWith('.json')) return true;
+  // binary files are imported as they are
+  try {
+    new TextDecoder('utf-8', { fatal: true }).decode(readFileSync(fpath));
+    return true;
+  } catch {
+    return false;
+  }
+}
 
 export default class JsModule extends Module {
   importVisitor = new ImportVisitor();
 
-  mergeImports(imports: { source: string, pattern?: string }[]) {
+  mergeImports(imports: Import[], eager = true) {
     const { fpath, packet } = this;
     const result: string[] = [];
-    for (const { source, pattern } of imports) {
+    for (const { source, pattern, regexp, ...options } of imports) {
       if (packet.browser[source] === false || source === 'heredoc') continue;
-      if (pattern) {
-        result.push(...glob.sync(pattern, { cwd: path.dirname(fpath) }))
+      if (regexp) {
+        // require.context('./icons', true, /\.svg$/)
+        const cwd = path.dirname(fpath);
+        const re = new RegExp(regexp.source, regexp.flags.replace(/[gy]/g, ''));
+        const files = glob.sync(pattern!, { cwd, nodir: true });
+        result.push(...files.filter(file => re.test(`./${path.relative(source, file)}`)));
+      } else if (pattern) {
+        const cwd = path.dirname(fpath);
+        const files = glob.sync(pattern, { cwd });
+        result.push(...files.filter(file => !isInlined(path.join(cwd, file), options, eager)));
       } else {
         result.push(source);
       }
@@ -58,8 +99,16 @@ export default class JsModule extends Module {
     return result;
   }
 
-  matchImport(code: string) {
-    const { app, file, fpath, importVisitor } = this;
+  syntaxError(err: unknown) {
+    const { app, fpath } = this;
+    if (err instanceof Error) {
+      err.message = err.message.replace('Syntax Error', `Syntax Error (${path.relative(app.root, fpath)})`)
+    }
+    return err;
+  }
+
+  visitImports(code: string): ImportAnalysis {
+    const { file, importVisitor } = this;
     let program: Program;
     try {
       program = parseSync(code, {
@@ -70,16 +119,67 @@ export default class JsModule extends Module {
         decoratorsBeforeExport: true,
       });
     } catch (err) {
-      if (err instanceof Error) {
-        err.message = err.message.replace('Syntax Error', `Syntax Error (${path.relative(app.root, fpath)})`)
-      }
-      throw err;
+      throw this.syntaxError(err);
     }
     importVisitor.visitProgram(program);
-    const { imports, dynamicImports, __esModule } = importVisitor;
+    return importVisitor;
+  }
+
+  /**
+   * Same as `visitImports()` but with swc_plugin_import_analysis.wasm, which saves
+   * passing the AST from Rust to JavaScript.
+   */
+  analyzeImports(code: string): ImportAnalysis {
+    const { file, fpath } = this;
+    if (!importAnalysisPlugin) importAnalysisPlugin = resolvePlugin('swc_plugin_import_analysis.wasm');
+    let result;
+    try {
+      result = transformSync(code, {
+        swcrc: false,
+        filename: fpath,
+        jsc: {
+          parser: /\.tsx?/i.test(file)
+            ? { syntax: 'typescript', tsx: true, decorators: true }
+            : { syntax: 'ecmascript', jsx: true, decorators: true, decoratorsBeforeExport: true },
+          target: 'es2022',
+          experimental: {
+            plugins: [[importAnalysisPlugin, {}]],
+          },
+        },
+      });
+    } catch (err) {
+      throw this.syntaxError(err);
+    }
+    const { value } = extractComment<ImportAnalysis>(result.code, 'porterImportAnalysis');
+    if (!value) throw new Error(`unable to analyze imports of ${file}`);
+    return value;
+  }
+
+  matchImport(code: string) {
+    const { imports, dynamicImports, __esModule } = this.app.swc === true
+      ? this.analyzeImports(code)
+      : this.visitImports(code);
     this.imports = this.mergeImports(imports);
-    this.dynamicImports = this.mergeImports(dynamicImports);
+    this.dynamicImports = this.mergeImports(dynamicImports, false);
     if (this.__esModule == null) this.__esModule = __esModule;
+    if (this.app.swc === true) this.matchWorkers();
+  }
+
+  /**
+   * `import W from './worker.js?worker'` is turned into `new Worker(new URL('./worker.js', import.meta.url))`
+   * by swc_plugin_porter, hence the workers are assets bundled separately rather than dependencies.
+   */
+  matchWorkers() {
+    const assets: Asset[] = [];
+    this.imports = this.imports!.filter(specifier => {
+      const [pathname, search = ''] = specifier.split('?');
+      const searchParams = new URLSearchParams(search);
+      if (searchParams.has('worker')) assets.push({ specifier: pathname, kind: 'worker' });
+      else if (searchParams.has('sharedworker')) assets.push({ specifier: pathname, kind: 'sharedWorker' });
+      else return true;
+      return false;
+    });
+    if (assets.length > 0) this.assets = assets;
   }
 
   /**
@@ -138,6 +238,7 @@ export default class JsModule extends Module {
     if (!this.imports && this.cache) {
       this.imports = this.cache.imports;
       this.dynamicImports = this.cache.dynamicImports;
+      this.assets = this.cache.assets;
       this.__esModule = this.cache.__esModule;
     }
     if (!this.imports) this.matchImport(code);
@@ -154,9 +255,26 @@ export default class JsModule extends Module {
 
     this.children = children.concat(dynamicChildren).filter(mod => !!mod) as Module[];
     this.dynamicChildren = dynamicChildren.filter(mod => !!mod) as Module[];
+    if (this.assets) await this.parseAssets(this.assets);
     this.status = MODULE_LOADED;
   }
 
+  /**
+   * The workers are parsed as entries of their own, and the wasm files referenced with
+   * `new URL('./foo.wasm', import.meta.url)` are parsed as dependencies to have them bundled,
+   * the rest of the assets are served as they are.
+   */
+  async parseAssets(assets: Asset[]) {
+    this.assets = assets;
+    for (const { specifier, kind } of assets) {
+      if (kind === 'worker' || kind === 'sharedWorker') {
+        await this.parseImport(`${specifier}?worker`);
+      } else if (specifier.endsWith('.wasm')) {
+        await this.parseImport(specifier);
+      }
+    }
+  }
+
   async load(): Promise<{ code: string, map?: RawSourceMap}> {
     const { fpath, app } = this;
     // fake entries will provide code directly
@@ -248,6 +366,29 @@ export default class JsModule extends Module {
     ].join('\n');
   }
 
+  /**
+   * The config of swc_plugin_porter_preset.wasm. The specifiers are left as they are since
+   * the loader resolves them with the dependencies passed to `porter.define()`, except the
+   * ones neglected with `false` in browser field or `resolve.fallback`, which are stubbed.
+   * The rest of swc-plugin-porter's options are not passed yet: the loader takes care of
+   * `import()`, `require.async()` and `.wasm` files itself, the manifest isn't settled until
+   * the packet is bundled, and CssModule doesn't export the class names of CSS modules.
+   */
+  presetConfig() {
+    const { packet, app } = this;
+    const resolve: Record<string, false> = {};
+    for (const neglected of [ packet.browser, app.resolve.fallback ]) {
+      for (const [ specifier, value ] of Object.entries(neglected)) {
+        if (value === false) resolve[specifier] = false;
+      }
+    }
+    return {
+      deheredoc: true,
+      globImport: true,
+      porter: { resolve, interop: true },
+    };
+  }
+
   async _transform({ code, map, minify }: TranspileOptions) {
     const { fpath, packet, app } = this;
 
@@ -255,7 +396,7 @@ export default class JsModule extends Module {
       return { code: this._declare(code), map };
     }
 
-    if (!plugins) plugins = loadPlugins();
+    if (!presetPlugin) presetPlugin = resolvePlugin('swc_plugin_porter_preset.wasm');
     let { keep_fnames: keep_classnames = false } = app.uglifyOptions || {};
     if (keep_classnames instanceof RegExp) keep_classnames = keep_classnames.test(fpath);
     const filenameRelative = path.relative(app.root, fpath);
@@ -280,7 +421,7 @@ export default class JsModule extends Module {
         },
         experimental: {
           plugins: [
-            ...plugins,
+            [presetPlugin, this.presetConfig()],
           ],
         },
         minify: minify ? (jsc.minify || {
@@ -304,14 +445,18 @@ export default class JsModule extends Module {
       },
       module: {
         type: 'commonjs',
+        // default imports are unwrapped by the interop helper of swc-plugin-porter
+        importInterop: 'node',
       },
       minify,
     });
-    await this.checkImports({ code: result.code, intermediate: true });
+    const { code: transformed, value: metadata = {} } = extractComment<Metadata>(result.code, 'porterMetadata');
+    await this.checkImports({ code: transformed, intermediate: true });
+    if (metadata.assets) await this.parseAssets(metadata.assets);
 
     return { ...result,
       // TODO customize module type
-      code: `porter.define(${JSON.stringify(this.id)},${JSON.stringify(this.imports)},function(require,exports,module){${result.code}});`,
+      code: `porter.define(${JSON.stringify(this.id)},${JSON.stringify(this.imports)},function(require,exports,module){${transformed}});`,
       map: result.map && JSON.parse(result.map),
     };
   }
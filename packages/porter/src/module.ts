@@ -14,6 +14,15 @@ export interface ModuleOptions {
   code?: string;
 }
 
+/**
+ * The assets referenced with `new URL(specifier, import.meta.url)` or `new Worker(...)`,
+ * which are reported by swc-plugin-porter.
+ */
+export interface Asset {
+  specifier: string;
+  kind: 'url' | 'worker' | 'sharedWorker';
+}
+
 export interface ModuleCache {
   code: string;
   map: RawSourceMap;
@@ -22,6 +31,7 @@ export interface ModuleCache {
   dynamicImports: string[];
   minified?: boolean;
   __esModule?: boolean;
+  assets?: Asset[];
 }
 
 export interface SourceOptions {
@@ -47,6 +57,7 @@ export default class Module {
   dynamicChildren: Module[] = [];
   imports?: string[];
   dynamicImports?: string[];
+  assets?: Asset[];
   __esModule?: boolean;
   entries: Module[];
   loaders: Record<string, any> = {};
@@ -215,12 +226,13 @@ export default class Module {
   }
 
   setCache(source: string, result: { code: string, map?: string | RawSourceMap, minified?: boolean }) {
-    const { app, imports = [], dynamicImports = [], __esModule } = this;
+    const { app, imports = [], dynamicImports = [], assets, __esModule } = this;
     const cache = {
       ...result,
       map: typeof result.map === 'string' ? JSON.parse(result.map) : result.map,
       imports,
       dynamicImports,
+      assets,
       __esModule,
     };
     app.cache.set(this.id, source, cache).catch(err => console.error(err));
@@ -270,7 +282,7 @@ export default class Module {
       const [pathname, search] = dep.split('?');
       dep = pathname;
       const searchParams = new URLSearchParams(search);
-      if (searchParams.has('worker')) {
+      if (searchParams.has('worker') || searchParams.has('sharedworker')) {
         const result: Record<string, any> = {};
         for (const key of searchParams.keys()) result[key] = searchParams.get(key);
         loaders['worker-loader'] = result;
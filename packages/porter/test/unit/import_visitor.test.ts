@@ -134,6 +134,28 @@ describe('ImportVisitor', function() {
     })
   });
 
+  describe('require.context', function() {
+    it("require.context('./icons/', true, /\\.svg$/i)", async function() {
+      const { imports } = await findAll("require.context('./icons/', true, /\\.svg$/i)");
+      assert.deepEqual(imports, [
+        { source: './icons', pattern: './icons/**/*', regexp: { source: '\\.svg$', flags: 'i' } },
+      ]);
+    });
+
+    it("require.context('./i18n', false, /\\.json$/, 'lazy')", async function() {
+      const { imports, dynamicImports } = await findAll("require.context('./i18n', false, /\\.json$/, 'lazy')");
+      assert.deepEqual(imports, []);
+      assert.deepEqual(dynamicImports, [
+        { source: './i18n', pattern: './i18n/*', regexp: { source: '\\.json$', flags: '' } },
+      ]);
+    });
+
+    it('require.context(dir)', async function() {
+      const { imports } = await findAll("const dir = './icons'; require.context(dir)");
+      assert.deepEqual(imports, []);
+    });
+  });
+
   describe('import css/less/sass', function() {
     it('import "./foo.scss"', async function() {
       const { imports, dynamicImports } = await findAll('import "./foo.scss";');
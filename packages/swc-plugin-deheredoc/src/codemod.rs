@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use swc_core::{
+    common::{comments::Comments, Span, Spanned},
+    ecma::{
+        ast::{Expr, Ident, ImportDecl, ImportSpecifier, MemberProp, Pat, Program, VarDecl, VarDeclarator},
+        visit::{Visit, VisitWith},
+    },
+};
+
+use crate::deheredoc::{deindent, heredoc_comment_pos, is_heredoc_require};
+
+/// Replaces the source text within `span` with `text`, an empty text removes it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement {
+    pub span: Span,
+    pub text: String,
+}
+
+/// The source-to-source variant of [crate::deheredoc_transform], which rewrites the heredoc
+/// calls into template literals instead of string literals, e.g.
+///
+/// ```js
+/// const html = heredoc(function() {/*
+///   <div>
+///     <span></span>
+///   </div>
+/// */});
+/// // =>
+/// const html = `<div>
+///   <span></span>
+/// </div>`;
+/// ```
+///
+/// The imports or requires of heredoc are removed as well, unless the names they bind
+/// are still referenced, e.g. by the heredoc calls that can't be rewritten or by the calls
+/// of `hd` in `const hd = require('heredoc')`. The replacements are sorted by position,
+/// and empty if there is nothing to do.
+pub fn deheredoc_codemod<C>(program: &Program, comments: &C) -> Vec<Replacement>
+where
+    C: Comments,
+{
+    let mut codemod = DeheredocCodemod {
+        comments,
+        calls: vec![],
+        imports: vec![],
+        requires: vec![],
+        references: Default::default(),
+    };
+    program.visit_with(&mut codemod);
+
+    let DeheredocCodemod { calls: mut replacements, imports, requires, references, .. } = codemod;
+    if !replacements.is_empty() {
+        let unused = |name: &String| !references.contains_key(name);
+        for (span, locals) in imports {
+            if locals.iter().all(unused) {
+                replacements.push(Replacement { span, text: String::new() });
+            }
+        }
+        for require in requires {
+            require.remove_unused(&unused, &mut replacements);
+        }
+    }
+    replacements.sort_by_key(|r| r.span.lo);
+    replacements
+}
+
+struct DeheredocCodemod<'a, C>
+where
+    C: Comments,
+{
+    comments: &'a C,
+    calls: Vec<Replacement>,
+    /// `import heredoc from 'heredoc'` and the locals bound.
+    imports: Vec<(Span, Vec<String>)>,
+    requires: Vec<RequireDecl>,
+    /// References other than the calls being replaced, counted by name.
+    references: HashMap<String, usize>,
+}
+
+/// `var a = 1, heredoc = require('heredoc');` which has to be tracked until the references
+/// are counted.
+struct RequireDecl {
+    span: Span,
+    /// The declarators and the names bound to `require('heredoc')`, the ones that aren't
+    /// requires or bind patterns other than identifiers are kept.
+    decls: Vec<(Span, Option<String>)>,
+}
+
+impl RequireDecl {
+    fn remove_unused(&self, unused: &dyn Fn(&String) -> bool, replacements: &mut Vec<Replacement>) {
+        let RequireDecl { span, decls } = self;
+        let is_removed = |i: usize| decls[i].1.as_ref().is_some_and(unused);
+        let Some(last_kept) = (0..decls.len()).rev().find(|&i| !is_removed(i)) else {
+            replacements.push(Replacement { span: *span, text: String::new() });
+            return;
+        };
+        // removes the commas along with the declarators
+        for i in 0..last_kept {
+            if is_removed(i) {
+                let span = decls[i].0.with_hi(decls[i + 1].0.lo);
+                replacements.push(Replacement { span, text: String::new() });
+            }
+        }
+        if last_kept + 1 < decls.len() {
+            let span = span.with_lo(decls[last_kept].0.hi).with_hi(decls.last().unwrap().0.hi);
+            replacements.push(Replacement { span, text: String::new() });
+        }
+    }
+}
+
+fn template_literal(text: &str) -> String {
+    let text = text.replace('\\', "\\\\").replace('`', "\\`").replace("${", "\\${");
+    format!("`{text}`")
+}
+
+impl<C> Visit for DeheredocCodemod<'_, C>
+where
+    C: Comments,
+{
+    fn visit_expr(&mut self, e: &Expr) {
+        let text = heredoc_comment_pos(e)
+            .and_then(|pos| self.comments.get_trailing(pos))
+            .and_then(|comments| comments.first().map(|comment| deindent(comment.text.to_string())));
+        match text {
+            Some(text) => self.calls.push(Replacement { span: e.span(), text: template_literal(&text) }),
+            // the calls left as is are counted as references in visit_ident
+            None => e.visit_children_with(self),
+        }
+    }
+
+    fn visit_ident(&mut self, id: &Ident) {
+        *self.references.entry(id.sym.to_string()).or_default() += 1;
+    }
+
+    fn visit_member_prop(&mut self, prop: &MemberProp) {
+        // `foo.heredoc` is not a reference
+        if let MemberProp::Computed(computed) = prop {
+            computed.visit_with(self);
+        }
+    }
+
+    fn visit_import_decl(&mut self, i: &ImportDecl) {
+        if &*i.src.value == "heredoc" {
+            let locals = i.specifiers.iter().map(|specifier| match specifier {
+                ImportSpecifier::Named(s) => s.local.sym.to_string(),
+                ImportSpecifier::Default(s) => s.local.sym.to_string(),
+                ImportSpecifier::Namespace(s) => s.local.sym.to_string(),
+            });
+            self.imports.push((i.span, locals.collect()));
+        }
+    }
+
+    fn visit_var_decl(&mut self, var: &VarDecl) {
+        let is_require = |decl: &VarDeclarator| decl.init.as_deref().is_some_and(is_heredoc_require);
+        if !var.decls.iter().any(is_require) {
+            return var.visit_children_with(self);
+        }
+        let mut decls = Vec::new();
+        for decl in var.decls.iter() {
+            let name = match &decl.name {
+                Pat::Ident(name) if is_require(decl) => Some(name.id.sym.to_string()),
+                _ => None,
+            };
+            if name.is_none() {
+                decl.visit_with(self);
+            }
+            decls.push((decl.span, name));
+        }
+        self.requires.push(RequireDecl { span: var.span, decls });
+    }
+}
@@ -1,16 +1,14 @@
 use swc_core::ecma::ast::BlockStmtOrExpr;
-use swc_core::plugin::{plugin_transform, proxies::TransformPluginProgramMetadata};
 use swc_core::{
     common::{comments::Comments, util::take::Take, BytePos, DUMMY_SP},
     ecma::{
         ast::{
             AssignExpr, BinExpr, CallExpr, Callee, Decl, Expr, ExprOrSpread, ImportDecl, Lit,
-            ModuleDecl, ModuleItem, Program, Stmt, Str, VarDeclarator,
+            ModuleDecl, ModuleItem, Stmt, Str, VarDeclarator,
         },
         atoms::JsWord,
         visit::{VisitMut, VisitMutWith},
     },
-    plugin::proxies::PluginCommentsProxy,
 };
 
 pub fn deheredoc_transform<C>(comments: C) -> impl VisitMut
@@ -27,98 +25,93 @@ where
     comments: C,
 }
 
-impl<C> Deheredoc<C>
-where
-    C: Comments,
-{
-    fn is_heredoc_call(&self, e: &Expr) -> bool {
-        if let Expr::Call(CallExpr {
-            callee: Callee::Expr(callee),
-            args,
-            ..
-        }) = e
-        {
-            if let Expr::Ident(id) = &**callee {
-                return &*id.sym == "heredoc" && args.len() == 1;
-            }
+pub(crate) fn is_heredoc_call(e: &Expr) -> bool {
+    if let Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..
+    }) = e
+    {
+        if let Expr::Ident(id) = &**callee {
+            return &*id.sym == "heredoc" && args.len() == 1;
         }
-        false
     }
+    false
+}
 
-    fn is_heredoc_require(&self, e: &Expr) -> bool {
-        if let Expr::Call(CallExpr {
-            callee: Callee::Expr(callee),
-            args,
-            ..
-        }) = e
-        {
-            if let Expr::Ident(id) = &**callee {
-                if &*id.sym != "require" {
-                    return false;
-                }
-                if let Some(ExprOrSpread { expr, .. }) = args.get(0) {
-                    if let Expr::Lit(Lit::Str(lit)) = &**expr {
-                        if &lit.value == "heredoc" {
-                            return true;
-                        }
+pub(crate) fn is_heredoc_require(e: &Expr) -> bool {
+    if let Expr::Call(CallExpr {
+        callee: Callee::Expr(callee),
+        args,
+        ..
+    }) = e
+    {
+        if let Expr::Ident(id) = &**callee {
+            if &*id.sym != "require" {
+                return false;
+            }
+            if let Some(ExprOrSpread { expr, .. }) = args.first() {
+                if let Expr::Lit(Lit::Str(lit)) = &**expr {
+                    if &lit.value == "heredoc" {
+                        return true;
                     }
                 }
             }
         }
-        false
     }
+    false
+}
 
-    fn deindent(&self, text: String) -> String {
-        let mut indent = usize::MAX;
-        for line in text.lines(){
-            if let Some(i) = line.find(|c: char| !c.is_whitespace()) {
-                if indent > i { indent = i; }
-            }
+pub(crate) fn deindent(text: String) -> String {
+    let mut indent = usize::MAX;
+    for line in text.lines(){
+        if let Some(i) = line.find(|c: char| !c.is_whitespace()) {
+            if indent > i { indent = i; }
         }
-        if indent == usize::MAX { return text; }
-        let mut result = String::new();
-        for line in text.lines() {
-            if line.len() > indent {
-                result.push_str(line.split_at(indent).1);
-                result.push_str("\n");
-            } else if result.len() > 0 {
-                result.push_str(line);
-                result.push_str("\n");
-            }
+    }
+    if indent == usize::MAX { return text; }
+    let mut result = String::new();
+    for line in text.lines() {
+        if line.len() > indent {
+            result.push_str(line.split_at(indent).1);
+            result.push('\n');
+        } else if !result.is_empty() {
+            result.push_str(line);
+            result.push('\n');
         }
-        result.trim_end().to_string()
     }
+    result.trim_end().to_string()
+}
 
-    fn extract_heredoc(&self, e: &mut Expr) -> Option<String> {
-        if !self.is_heredoc_call(e) { return None; }
-        if let Expr::Call(CallExpr { args, .. }) = e {
-            if let Some(ExprOrSpread { expr, .. }) = args.get(0) {
-                if let Expr::Fn(func) = &**expr {
-                    for stmt in func.function.body.iter() {
-                        let comments = self.comments.take_trailing(BytePos(stmt.span.lo.0 + 1));
-                        if let Some(comments) = comments {
-                            for comment in comments.iter() {
-                                return Some(self.deindent(comment.text.to_string()));
-                            }
-                        }
-                    }
+/// Position of the `/* ... */` comment in `heredoc(function() {/* ... */})`, which is the
+/// trailing comment of the opening brace.
+pub(crate) fn heredoc_comment_pos(e: &Expr) -> Option<BytePos> {
+    if !is_heredoc_call(e) { return None; }
+    if let Expr::Call(CallExpr { args, .. }) = e {
+        if let Some(ExprOrSpread { expr, .. }) = args.first() {
+            if let Expr::Fn(func) = &**expr {
+                if let Some(stmt) = &func.function.body {
+                    return Some(BytePos(stmt.span.lo.0 + 1));
                 }
-                if let Expr::Arrow(func) = &**expr {
-                    match &*func.body {
-                        BlockStmtOrExpr::BlockStmt(block) => {
-                            let comments = self.comments.take_trailing(BytePos(block.span.lo.0 + 1));
-                            if let Some(comments) = comments {
-                                for comment in comments.iter() {
-                                    return Some(self.deindent(comment.text.to_string()));
-                                }
-                            }
-                        },
-                        _ => {},
-                    }
+            }
+            if let Expr::Arrow(func) = &**expr {
+                if let BlockStmtOrExpr::BlockStmt(block) = &*func.body {
+                    return Some(BytePos(block.span.lo.0 + 1));
                 }
             }
         }
-        None
+    }
+    None
+}
+
+impl<C> Deheredoc<C>
+where
+    C: Comments,
+{
+    fn extract_heredoc(&self, e: &mut Expr) -> Option<String> {
+        let pos = heredoc_comment_pos(e)?;
+        let comments = self.comments.take_trailing(pos);
+        comments.as_ref().and_then(|c| c.first()).map(|comment| deindent(comment.text.to_string()))
     }
 }
 
@@ -137,13 +130,10 @@ where
     fn visit_mut_stmt(&mut self, s: &mut Stmt) {
         s.visit_mut_children_with(self);
 
-        match s {
-            Stmt::Decl(Decl::Var(var)) => {
-                if var.decls.is_empty() {
-                    s.take();
-                }
+        if let Stmt::Decl(Decl::Var(var)) = s {
+            if var.decls.is_empty() {
+                s.take();
             }
-            _ => {}
         }
     }
 
@@ -180,7 +170,7 @@ where
                     value: JsWord::from(text),
                     raw: None,
                 }))));
-            } else if self.is_heredoc_require(init) {
+            } else if is_heredoc_require(init) {
                 v.name.take();
             }
         }
@@ -203,24 +193,24 @@ where
     fn visit_mut_assign_expr(&mut self, e: &mut AssignExpr) {
         e.visit_mut_children_with(self);
 
-        if let Some(text) = self.extract_heredoc(&mut *e.right) {
-            e.right = Box::new(Expr::Lit(Lit::Str(Str {
+        if let Some(text) = self.extract_heredoc(&mut e.right) {
+            *e.right = Expr::Lit(Lit::Str(Str {
                 span: e.span,
                 value: JsWord::from(text),
                 raw: None,
-            })));
+            }));
         }
     }
 
     fn visit_mut_bin_expr(&mut self, e: &mut BinExpr) {
         e.visit_mut_children_with(self);
 
-        if let Some(text) = self.extract_heredoc(&mut *e.right) {
-            e.right = Box::new(Expr::Lit(Lit::Str(Str {
+        if let Some(text) = self.extract_heredoc(&mut e.right) {
+            *e.right = Expr::Lit(Lit::Str(Str {
                 span: e.span,
                 value: JsWord::from(text),
                 raw: None,
-            })));
+            }));
         }
     }
 
@@ -230,11 +220,11 @@ where
         for i in 0..e.args.len() {
             let arg = &mut e.args[i];
             if let Some(text) = self.extract_heredoc(&mut arg.expr) {
-                arg.expr = Box::new(Expr::Lit(Lit::Str(Str {
+                *arg.expr = Expr::Lit(Lit::Str(Str {
                     span: e.span,
                     value: JsWord::from(text),
                     raw: None,
-                })));
+                }));
             }
         }
     }
@@ -247,27 +237,3 @@ where
         }
     }
 }
-
-/// An example plugin function with macro support.
-/// `plugin_transform` macro interop pointers into deserialized structs, as well
-/// as returning ptr back to host.
-///
-/// It is possible to opt out from macro by writing transform fn manually
-/// if plugin need to handle low-level ptr directly via
-/// `__transform_plugin_process_impl(
-///     ast_ptr: *const u8, ast_ptr_len: i32,
-///     unresolved_mark: u32, should_enable_comments_proxy: i32) ->
-///     i32 /*  0 for success, fail otherwise.
-///             Note this is only for internal pointer interop result,
-///             not actual transform result */`
-///
-/// This requires manual handling of serialization / deserialization from ptrs.
-/// Refer swc_plugin_macro to see how does it work internally.
-#[plugin_transform]
-pub fn deheredoc_plugin(
-    mut program: Program,
-    _metadata: TransformPluginProgramMetadata,
-) -> Program {
-    program.visit_mut_with(&mut deheredoc_transform(PluginCommentsProxy));
-    program
-}
@@ -1,2 +1,3 @@
 pub use self::deheredoc::*;
+pub mod codemod;
 pub mod deheredoc;
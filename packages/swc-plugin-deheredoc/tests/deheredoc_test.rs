@@ -1,32 +1,10 @@
-use std::path::PathBuf;
-
-use swc_core::ecma::{
-    transforms::testing::{test, test_fixture},
-    visit::as_folder,
-};
+use porter_swc_common::testing::FixtureOptions;
+use swc_core::ecma::visit::as_folder;
 use swc_plugin_deheredoc::deheredoc_transform;
 
-// An example to test plugin transform.
-// Recommended strategy to test plugin's transform is verify
-// the Visitor's behavior, instead of trying to run `process_transform` with mocks
-// unless explicitly required to do so.
-// test!(
-//     Default::default(),
-//     |t: Tester| as_folder(deheredoc_transform(t.comments.clone())),
-//     boo,
-//     r#"var foo = heredoc(function() {/* foobar */});"#,
-//     r#"var foo = "foobar";"#
-// );
-
-#[testing::fixture("tests/fixture/**/input.js")]
-fn fixture(input: PathBuf) {
-    let output = input.parent().unwrap().join("output.js");
-
-    test_fixture(
-        Default::default(),
-        &|t| as_folder(deheredoc_transform(t.comments.clone())),
-        &input,
-        &output,
-        Default::default(),
-    );
-}
+porter_swc_common::fixture!(
+    fixture,
+    "tests/fixture/**/input.js",
+    FixtureOptions { idempotent: true, ..Default::default() },
+    |_, t| as_folder(deheredoc_transform(t.comments.clone()))
+);
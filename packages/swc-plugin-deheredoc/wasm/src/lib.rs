@@ -0,0 +1,12 @@
+use deheredoc::deheredoc_transform;
+use swc_core::{
+    ecma::{ast::Program, visit::VisitMutWith},
+    plugin::{plugin_transform, proxies::{PluginCommentsProxy, TransformPluginProgramMetadata}},
+};
+
+/// Turns the heredoc functions into string literals, see [deheredoc_transform].
+#[plugin_transform]
+pub fn deheredoc_plugin(mut program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
+    program.visit_mut_with(&mut deheredoc_transform(PluginCommentsProxy));
+    program
+}
@@ -0,0 +1,318 @@
+use swc_core::{
+    common::{comments::Comments, BytePos, SourceFile, Span, Spanned},
+    ecma::{
+        ast::{
+            AssignExpr, CallExpr, Expr, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier, Lit, Module, ModuleDecl,
+            ModuleItem, Program, Stmt, VarDeclarator,
+        },
+        visit::{Visit, VisitWith},
+    },
+};
+
+use crate::{GlobImport, Imported};
+
+/// Marks the code materialized by [glob_import_codemod], which can be regenerated with
+/// [glob_import_revert] followed by [glob_import_codemod].
+pub const HEADER: &str = "@generated by `porter-swc --codemod glob-import`";
+
+/// Replaces the source text within `span` with `text`, an empty text removes it and an
+/// empty span inserts the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement {
+    pub span: Span,
+    pub text: String,
+}
+
+/// The source-to-source variant of [crate::glob_import_transform], which writes the files
+/// matched by `import.meta.glob()` into the source file, e.g.
+///
+/// ```js
+/// const files = import.meta.glob('./data/*.json', { eager: true });
+/// // =>
+/// // @generated by `porter-swc --codemod glob-import`
+/// import * as __glob_0_0 from './data/a.json';
+///
+/// // @generated by `porter-swc --codemod glob-import` from import.meta.glob('./data/*.json', { eager: true })
+/// const files = {
+///   './data/a.json': __glob_0_0
+/// };
+/// ```
+///
+/// The lazy ones are written as maps of `() => import()` instead, and the ones with
+/// `{ as: 'url' }` as maps of `new URL()`. With `{ import: 'foo' }`
+/// the named export is imported, which is not inlined for JSON files but imported by name
+/// as well, and `{ inline: true }` is ignored, which imports the module namespaces as if
+/// it weren't specified.
+/// The replacements are sorted by position.
+pub fn glob_import_codemod(program: &Program, filepath: String, fm: &SourceFile) -> Vec<Replacement> {
+    let mut codemod = GlobImportCodemod {
+        glob: GlobImport { filepath, module_items: Vec::new(), glob_index: 0 },
+        fm,
+        statement: None,
+        last_import: None,
+        imports: Vec::new(),
+        quote: '"',
+        replacements: Vec::new(),
+    };
+    program.visit_with(&mut codemod);
+
+    if !codemod.imports.is_empty() {
+        let mut text = format!("// {HEADER}\n");
+        for (local, specifier, imported) in &codemod.imports {
+            let specifier = quote(specifier, codemod.quote);
+            match imported {
+                Imported::Namespace => text.push_str(&format!("import * as {local} from {specifier};\n")),
+                Imported::Default => text.push_str(&format!("import {local} from {specifier};\n")),
+                Imported::Named(imported) => {
+                    text.push_str(&format!("import {{ {imported} as {local} }} from {specifier};\n"))
+                }
+            }
+        }
+        // right after the existing imports, or at the top of the file
+        let pos = match codemod.last_import {
+            Some(hi) => {
+                let hi = offset(fm, hi);
+                match fm.src[hi..].find('\n') {
+                    Some(i) => hi + i + 1,
+                    None => {
+                        text.insert(0, '\n');
+                        fm.src.len()
+                    }
+                }
+            }
+            None => {
+                text.push('\n');
+                0
+            }
+        };
+        let pos = fm.start_pos + BytePos(pos as u32);
+        // goes before the header of the statement at the same position
+        codemod.replacements.insert(0, Replacement { span: Span::new(pos, pos, Default::default()), text });
+    }
+    codemod.replacements.sort_by_key(|r| r.span.lo);
+    codemod.replacements
+}
+
+/// Puts the `import.meta.glob()` calls back in place of the code marked by [HEADER], which
+/// is the reverse of [glob_import_codemod].
+pub fn glob_import_revert<C>(program: &Program, comments: &C) -> Vec<Replacement>
+where
+    C: Comments,
+{
+    let mut revert = GlobImportRevert { comments, calls: Vec::new(), replacements: Vec::new() };
+    program.visit_with(&mut revert);
+    revert.replacements.sort_by_key(|r| r.span.lo);
+    revert.replacements
+}
+
+fn offset(fm: &SourceFile, pos: BytePos) -> usize {
+    (pos - fm.start_pos).0 as usize
+}
+
+fn quote(value: &str, quote: char) -> String {
+    let value = value.replace('\\', "\\\\").replace(quote, &format!("\\{quote}"));
+    format!("{quote}{value}{quote}")
+}
+
+fn is_glob_local(local: &str) -> bool {
+    local.starts_with("__glob_")
+}
+
+struct GlobImportCodemod<'a> {
+    glob: GlobImport,
+    fm: &'a SourceFile,
+    /// The innermost statement, above which the header is written.
+    statement: Option<Span>,
+    last_import: Option<BytePos>,
+    imports: Vec<(String, String, Imported)>,
+    /// Follows the quotes of the first pattern.
+    quote: char,
+    replacements: Vec<Replacement>,
+}
+
+impl GlobImportCodemod<'_> {
+    fn materialize(&mut self, call: &CallExpr) {
+        let (Some(statement), Some(arg)) = (self.statement, call.args.first()) else { return };
+        let Expr::Lit(Lit::Str(pattern)) = &*arg.expr else { return };
+        let eager = call.args.get(1).is_some_and(|options| self.glob.is_eager(options));
+        let imported = self.glob.imported(call.args.get(1));
+        let url = call.args.get(1).is_some_and(|options| self.glob.is_url(options));
+        let src = &self.fm.src;
+        if self.replacements.is_empty() {
+            self.quote = match pattern.raw.as_deref().and_then(|raw| raw.chars().next()) {
+                Some('\'') => '\'',
+                _ => '"',
+            };
+        }
+
+        let lo = offset(self.fm, statement.lo);
+        let line_start = src[..lo].rfind('\n').map_or(0, |i| i + 1);
+        let indent: String = src[line_start..lo].chars().take_while(|c| c.is_whitespace()).collect();
+        // the header takes one line
+        let mut call_text = String::new();
+        for line in src[offset(self.fm, call.span.lo)..offset(self.fm, call.span.hi)].lines().map(str::trim) {
+            if line.starts_with(')') {
+                call_text.truncate(call_text.trim_end_matches(',').len());
+            } else if !call_text.is_empty() && !call_text.ends_with('(') {
+                call_text.push(' ');
+            }
+            call_text.push_str(line);
+        }
+
+        let mut entries = Vec::new();
+        for (index, specifier) in self.glob.specifiers(&pattern.value).into_iter().enumerate() {
+            let key = quote(&specifier, self.quote);
+            let value = if url {
+                let href = format!("new URL({key}, import.meta.url).href");
+                if eager { href } else { format!("() => Promise.resolve({href})") }
+            } else if eager {
+                let local = format!("__glob_{}_{}", self.glob.glob_index, index);
+                self.imports.push((local.clone(), specifier, imported.clone()));
+                local
+            } else if let Some(imported) = imported.export_name() {
+                format!("() => import({key}).then((m) => m.{imported})")
+            } else {
+                format!("() => import({key})")
+            };
+            entries.push(format!("{indent}  {key}: {value}"));
+        }
+        if eager {
+            self.glob.glob_index += 1;
+        }
+        let object = if entries.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{\n{}\n{indent}}}", entries.join(",\n"))
+        };
+
+        let header = format!("// {HEADER} from {call_text}\n{indent}");
+        self.replacements.push(Replacement { span: statement.with_hi(statement.lo), text: header });
+        self.replacements.push(Replacement { span: call.span, text: object });
+    }
+}
+
+impl Visit for GlobImportCodemod<'_> {
+    fn visit_stmt(&mut self, s: &Stmt) {
+        let statement = self.statement.replace(s.span());
+        s.visit_children_with(self);
+        self.statement = statement;
+    }
+
+    fn visit_module_decl(&mut self, decl: &ModuleDecl) {
+        let statement = self.statement.replace(decl.span());
+        decl.visit_children_with(self);
+        self.statement = statement;
+    }
+
+    fn visit_import_decl(&mut self, i: &ImportDecl) {
+        self.last_import = Some(i.span.hi);
+    }
+
+    fn visit_var_declarator(&mut self, v: &VarDeclarator) {
+        v.visit_children_with(self);
+
+        if let Some(Expr::Call(call)) = v.init.as_deref() {
+            if self.glob.is_glob_import(call) {
+                self.materialize(call);
+            }
+        }
+    }
+
+    fn visit_assign_expr(&mut self, e: &AssignExpr) {
+        e.visit_children_with(self);
+
+        if let Expr::Call(call) = &*e.right {
+            if self.glob.is_glob_import(call) {
+                self.materialize(call);
+            }
+        }
+    }
+}
+
+struct GlobImportRevert<'a, C>
+where
+    C: Comments,
+{
+    comments: &'a C,
+    /// The calls in the headers of current statement, in the order of the objects.
+    calls: Vec<String>,
+    replacements: Vec<Replacement>,
+}
+
+impl<C> GlobImportRevert<'_, C>
+where
+    C: Comments,
+{
+    fn enter(&mut self, span: Span) -> Vec<String> {
+        let mut calls = Vec::new();
+        for comment in self.comments.get_leading(span.lo).unwrap_or_default() {
+            if let Some(call) = comment.text.trim().strip_prefix(HEADER).and_then(|rest| rest.strip_prefix(" from ")) {
+                calls.push(call.to_string());
+                self.replacements.push(Replacement { span: comment.span, text: String::new() });
+            }
+        }
+        std::mem::replace(&mut self.calls, calls)
+    }
+
+    fn revert(&mut self, e: &Expr) {
+        if let Expr::Object(object) = e {
+            if !self.calls.is_empty() {
+                let text = self.calls.remove(0);
+                self.replacements.push(Replacement { span: object.span, text });
+            }
+        }
+    }
+}
+
+impl<C> Visit for GlobImportRevert<'_, C>
+where
+    C: Comments,
+{
+    fn visit_module(&mut self, m: &Module) {
+        m.visit_children_with(self);
+
+        let generated = m.body.iter().filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) => match decl.specifiers.as_slice() {
+                [ImportSpecifier::Default(ImportDefaultSpecifier { local, .. })]
+                | [ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. })]
+                | [ImportSpecifier::Named(ImportNamedSpecifier { local, .. })]
+                    if is_glob_local(&local.sym) => Some(decl.span),
+                _ => None,
+            },
+            _ => None,
+        });
+        let Some(span) = generated.reduce(|a, b| a.to(b)) else { return };
+        let header = self.comments.get_leading(span.lo).unwrap_or_default();
+        let lo = match header.iter().find(|comment| comment.text.trim() == HEADER) {
+            Some(comment) => comment.span.lo,
+            None => span.lo,
+        };
+        self.replacements.push(Replacement { span: span.with_lo(lo), text: String::new() });
+    }
+
+    fn visit_stmt(&mut self, s: &Stmt) {
+        let calls = self.enter(s.span());
+        s.visit_children_with(self);
+        self.calls = calls;
+    }
+
+    fn visit_module_decl(&mut self, decl: &ModuleDecl) {
+        let calls = self.enter(decl.span());
+        decl.visit_children_with(self);
+        self.calls = calls;
+    }
+
+    fn visit_var_declarator(&mut self, v: &VarDeclarator) {
+        v.visit_children_with(self);
+
+        if let Some(init) = &v.init {
+            self.revert(init);
+        }
+    }
+
+    fn visit_assign_expr(&mut self, e: &AssignExpr) {
+        e.visit_children_with(self);
+
+        self.revert(&e.right);
+    }
+}
@@ -0,0 +1,222 @@
+use porter_swc_common::{
+    ast::{arrow, arrow_with, call, ident, ident_expr, lazy_import, member, member_chain, new_expr, str_lit},
+    diagnostics::error,
+    glob::glob,
+};
+use regex::Regex;
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::ast::{
+        BinExpr, BinaryOp, BlockStmt, BlockStmtOrExpr, CallExpr, Callee, ComputedPropName, Expr, IfStmt, KeyValueProp,
+        Lit, MemberExpr, MemberProp, ObjectLit, ParenExpr, Prop, PropName, PropOrSpread, Regex as RegexLit,
+        ReturnStmt, Stmt, Str, ThrowStmt, UnaryExpr, UnaryOp,
+    },
+};
+
+use crate::GlobImport;
+
+/// How the modules in the context are loaded, same as the fourth argument of webpack's
+/// `require.context()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    /// `context(key)` returns the module, which is required eagerly.
+    Sync,
+    /// `context(key)` returns a promise of the module, which is imported on demand.
+    Lazy,
+}
+
+/// The arguments of `require.context(directory, recursive, regExp, mode)`.
+struct Context {
+    directory: String,
+    recursive: bool,
+    regexp: Regex,
+    mode: Mode,
+}
+
+/// Translates the regexp literal into Rust, the flags that don't change what is matched
+/// are dropped, e.g. `g`. Lookarounds and backreferences are not supported.
+fn regexp(lit: &RegexLit) -> Result<Regex, String> {
+    let mut flags = String::new();
+    for flag in lit.flags.chars() {
+        match flag {
+            'i' | 'm' | 's' => flags.push(flag),
+            'g' | 'y' | 'u' | 'd' => {}
+            _ => return Err(format!("unsupported regexp flag `{flag}`")),
+        }
+    }
+    let pattern = if flags.is_empty() { lit.exp.to_string() } else { format!("(?{flags}){}", lit.exp) };
+    Regex::new(&pattern).map_err(|err| format!("unsupported regexp /{}/: {err}", lit.exp))
+}
+
+impl GlobImport {
+    pub(crate) fn is_require_context(&self, n: &CallExpr) -> bool {
+        if let Callee::Expr(expr) = &n.callee {
+            if let Expr::Member(MemberExpr { obj, prop: MemberProp::Ident(prop), .. }) = &**expr {
+                if let Expr::Ident(obj) = &**obj {
+                    return &*obj.sym == "require" && &*prop.sym == "context";
+                }
+            }
+        }
+        false
+    }
+
+    /// Parses the arguments, which have to be literals since the directory is scanned
+    /// at compile time. The defaults are the same as webpack's.
+    fn context_args(&self, n: &CallExpr) -> Result<Context, String> {
+        let mut args = n.args.iter().map(|arg| &*arg.expr);
+        let directory = match args.next() {
+            Some(Expr::Lit(Lit::Str(value))) => value.value.trim_end_matches('/').to_string(),
+            _ => return Err("require.context() requires the directory as string literal".to_string()),
+        };
+        let recursive = match args.next() {
+            None => true,
+            Some(Expr::Lit(Lit::Bool(value))) => value.value,
+            Some(_) => return Err("the second argument of require.context() should be boolean literal".to_string()),
+        };
+        let regexp = match args.next() {
+            None => Regex::new(r"^\./.*$").unwrap(),
+            Some(Expr::Lit(Lit::Regex(lit))) => self::regexp(lit)?,
+            Some(_) => return Err("the third argument of require.context() should be regexp literal".to_string()),
+        };
+        let mode = match args.next() {
+            None => Mode::Sync,
+            Some(Expr::Lit(Lit::Str(value))) if &*value.value == "sync" => Mode::Sync,
+            Some(Expr::Lit(Lit::Str(value))) if &*value.value == "lazy" => Mode::Lazy,
+            Some(Expr::Lit(Lit::Str(value))) => return Err(format!("unsupported require.context() mode \"{}\"", value.value)),
+            Some(_) => return Err("the fourth argument of require.context() should be string literal".to_string()),
+        };
+        Ok(Context { directory, recursive, regexp, mode })
+    }
+
+    /// The keys and specifiers of the files in the context, where the keys are relative
+    /// to the directory, e.g. `./a.svg`, and tested against the regexp the same way as
+    /// webpack. The directory is scanned the same way as [GlobImport::glob], hence the
+    /// files and directories starting with `.` are skipped.
+    fn context_files(&self, context: &Context) -> Vec<(String, String)> {
+        let dir = self.resolve(&context.directory);
+        let pattern = if context.recursive { "**/*" } else { "*" };
+        let mut files = Vec::new();
+        for path in glob(&dir, pattern) {
+            if !path.is_file() {
+                continue;
+            }
+            let filename = path.strip_prefix(&dir).unwrap().to_str().unwrap();
+            let key = format!("./{filename}");
+            if context.regexp.is_match(&key) {
+                files.push((key, format!("{}/{filename}", context.directory)));
+            }
+        }
+        files
+    }
+
+    /// Replaces `require.context('./icons', true, /\.svg$/)` with the context function
+    /// of the matched files:
+    ///
+    /// ```js
+    /// ((modules) => Object.assign((key) => {
+    ///     if (!Object.prototype.hasOwnProperty.call(modules, key))
+    ///         throw Object.assign(new Error("Cannot find module '" + key + "'"), { code: "MODULE_NOT_FOUND" });
+    ///     return modules[key]();
+    /// }, {
+    ///     keys: () => Object.keys(modules),
+    ///     resolve: (key) => "./icons" + key.slice(1),
+    ///     id: "./icons"
+    /// }))({
+    ///     "./a.svg": () => require("./icons/a.svg")
+    /// });
+    /// ```
+    ///
+    /// The modules are required rather than imported since `require.context()` is mostly
+    /// found in CommonJS, which shall not be turned into ES module by hoisted imports.
+    /// They are imported with `() => import("./icons/a.svg")` in lazy mode instead, hence
+    /// `context(key)` returns a promise, which is rejected if the key isn't in the context.
+    /// `resolve(key)` returns the specifier,
+    /// which is the closest thing to webpack's module id.
+    pub(crate) fn context(&mut self, n: &CallExpr) -> Option<Box<Expr>> {
+        let context = match self.context_args(n) {
+            Ok(context) => context,
+            Err(message) => {
+                error(n.span, &message);
+                return None;
+            }
+        };
+        let mut props = Vec::new();
+        for (key, specifier) in self.context_files(&context) {
+            let value = match context.mode {
+                Mode::Sync => arrow(&[], call(ident_expr("require"), vec![str_lit(&specifier)])),
+                Mode::Lazy => lazy_import(&specifier, None),
+            };
+            let kv = KeyValueProp { key: PropName::Str(Str::from(key)), value: Box::new(value) };
+            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(kv))));
+        }
+        let modules = Expr::Object(ObjectLit { span: DUMMY_SP, props });
+        Some(Box::new(call(self.context_fn(&context.directory, context.mode), vec![modules])))
+    }
+
+    /// `(modules) => Object.assign((key) => { ... }, { keys, resolve, id })`, the keys that
+    /// aren't in the context fail the same way as `require()` or `import()` of a missing module.
+    fn context_fn(&self, directory: &str, mode: Mode) -> Expr {
+        let key = || ident_expr("key");
+        let module = Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(ident_expr("modules")),
+            prop: MemberProp::Computed(ComputedPropName { span: DUMMY_SP, expr: Box::new(key()) }),
+        });
+        let message = Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: BinaryOp::Add,
+            left: Box::new(Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                op: BinaryOp::Add,
+                left: Box::new(str_lit("Cannot find module '")),
+                right: Box::new(key()),
+            })),
+            right: Box::new(str_lit("'")),
+        });
+        let code = KeyValueProp { key: PropName::Ident(ident("code")), value: Box::new(str_lit("MODULE_NOT_FOUND")) };
+        let code = ObjectLit { span: DUMMY_SP, props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(code)))] };
+        let err = call(
+            member_chain(&["Object", "assign"]),
+            vec![new_expr(ident_expr("Error"), vec![message]), Expr::Object(code)],
+        );
+        let missing = match mode {
+            Mode::Sync => Stmt::Throw(ThrowStmt { span: DUMMY_SP, arg: Box::new(err) }),
+            Mode::Lazy => Stmt::Return(ReturnStmt {
+                span: DUMMY_SP,
+                arg: Some(Box::new(call(member_chain(&["Promise", "reject"]), vec![err]))),
+            }),
+        };
+        let has_own = call(
+            member_chain(&["Object", "prototype", "hasOwnProperty", "call"]),
+            vec![ident_expr("modules"), key()],
+        );
+        let stmts = vec![
+            Stmt::If(IfStmt {
+                span: DUMMY_SP,
+                test: Box::new(Expr::Unary(UnaryExpr { span: DUMMY_SP, op: UnaryOp::Bang, arg: Box::new(has_own) })),
+                cons: Box::new(missing),
+                alt: None,
+            }),
+            Stmt::Return(ReturnStmt { span: DUMMY_SP, arg: Some(Box::new(call(module, vec![]))) }),
+        ];
+        let context = arrow_with(&["key"], BlockStmtOrExpr::BlockStmt(BlockStmt { span: DUMMY_SP, stmts }));
+        let resolved = Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: BinaryOp::Add,
+            left: Box::new(str_lit(directory)),
+            right: Box::new(call(member(key(), "slice"), vec![Expr::Lit(Lit::Num(1.0.into()))])),
+        });
+        let props = [
+            ("keys", arrow(&[], call(member_chain(&["Object", "keys"]), vec![ident_expr("modules")]))),
+            ("resolve", arrow(&["key"], resolved)),
+            ("id", str_lit(directory)),
+        ];
+        let props = props.into_iter().map(|(name, value)| {
+            let kv = KeyValueProp { key: PropName::Ident(ident(name)), value: Box::new(value) };
+            PropOrSpread::Prop(Box::new(Prop::KeyValue(kv)))
+        });
+        let object = Expr::Object(ObjectLit { span: DUMMY_SP, props: props.collect() });
+        let body = call(member_chain(&["Object", "assign"]), vec![context, object]);
+        Expr::Paren(ParenExpr { span: DUMMY_SP, expr: Box::new(arrow(&["modules"], body)) })
+    }
+}
@@ -1,11 +1,47 @@
-use swc_core::{ecma::{
-    ast::{Program, CallExpr, MemberExpr, MetaPropExpr, Callee, Expr, MetaPropKind, MemberProp, PropOrSpread, ExprOrSpread, Prop, KeyValueProp, PropName, Lit, AssignExpr, ObjectLit, Str, Import, VarDeclarator, Ident, ModuleItem, ModuleDecl, ImportDecl, ImportSpecifier, ImportDefaultSpecifier, ArrowExpr, BlockStmtOrExpr},
-    visit::{as_folder, FoldWith, VisitMut, VisitMutWith},
+use swc_core::ecma::{
+    ast::{CallExpr, MemberExpr, MetaPropExpr, Callee, Expr, MetaPropKind, MemberProp, PropOrSpread, ExprOrSpread, Prop, KeyValueProp, PropName, Lit, AssignExpr, ObjectLit, Str, VarDeclarator, ModuleItem},
+    visit::{VisitMut, VisitMutWith},
     utils::prepend_stmts,
-}, common::DUMMY_SP};
-use swc_core::plugin::{plugin_transform, proxies::TransformPluginProgramMetadata, metadata::TransformPluginMetadataContextKind};
-use glob::glob;
-use std::path::Path;
+};
+use std::{fs, path::{Path, PathBuf}};
+use swc_core::common::Span;
+use porter_swc_common::ast::{
+    arrow, call, default_specifier, ident, ident_expr, import_decl, lazy_import, member, member_chain,
+    named_specifier, namespace_specifier, new_url, str_lit,
+};
+use porter_swc_common::{diagnostics::error, glob::glob};
+use swc_plugin_json_module::{parse_json, JsonValue};
+
+/// The files that are modules rather than data, which are never inlined.
+const MODULE_EXTENSIONS: &[&str] = &[
+    "js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts", "css", "less", "sass", "scss", "wasm",
+];
+
+pub mod codemod;
+mod context;
+
+/// What is imported from the matched files, which is the module namespace unless the
+/// `import` option says otherwise, same as vite.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Imported {
+    /// `import * as __glob_0_0 from './foo.js'`
+    Namespace,
+    /// `{ import: 'default' }`
+    Default,
+    /// `{ import: 'foo' }`
+    Named(String),
+}
+
+impl Imported {
+    /// The export read off the module namespace of the dynamic imports.
+    fn export_name(&self) -> Option<&str> {
+        match self {
+            Imported::Namespace => None,
+            Imported::Default => Some("default"),
+            Imported::Named(name) => Some(name),
+        }
+    }
+}
 
 pub fn glob_import_transform(filepath: String) -> impl VisitMut {
     GlobImport { filepath, module_items: Vec::new(), glob_index: 0 }
@@ -13,7 +49,8 @@ pub fn glob_import_transform(filepath: String) -> impl VisitMut {
 
 pub struct GlobImport {
     filepath: String,
-    module_items: Vec<(String, String)>,
+    /// The local name, the specifier, and what is imported.
+    module_items: Vec<(String, String, Imported)>,
     glob_index: i32,
 }
 
@@ -30,96 +67,193 @@ impl GlobImport {
         false
     }
 
-    fn is_eager(&self, n: &ExprOrSpread) -> bool {
+    fn option<'a>(&self, n: &'a ExprOrSpread, name: &str) -> Option<&'a Expr> {
         if let Expr::Object(options) = &*n.expr {
             for prop in options.props.iter() {
                 if let PropOrSpread::Prop(prop) = prop {
                     if let Prop::KeyValue(KeyValueProp { key: PropName::Ident(key), value }) = &**prop {
-                        if &*key.sym == "eager" {
-                            if let Expr::Lit(Lit::Bool(value)) = &**value {
-                                return value.value;
-                            }
+                        if &*key.sym == name {
+                            return Some(value);
                         }
                     }
                 }
             }
         }
+        None
+    }
+
+    fn is_eager(&self, n: &ExprOrSpread) -> bool {
+        if let Some(Expr::Lit(Lit::Bool(value))) = self.option(n, "eager") {
+            return value.value;
+        }
         false
     }
 
-    fn import_decl(&self, local: &str, src: &str) -> ModuleItem {
-       ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl {
-            span: DUMMY_SP,
-            specifiers: vec![
-                ImportSpecifier::Default(
-                    ImportDefaultSpecifier {
-                        span: DUMMY_SP,
-                        local: Ident { span: DUMMY_SP, sym: local.into(), optional: false },
+    /// `{ as: 'url' }` takes the files as assets, see [GlobImport::asset_url].
+    fn is_url(&self, n: &ExprOrSpread) -> bool {
+        matches!(self.option(n, "as"), Some(Expr::Lit(Lit::Str(value))) if &*value.value == "url")
+    }
+
+    /// `{ import: 'foo' }` imports the named export only, which is `undefined` if the
+    /// module has no such export. The module namespace is imported if not specified, hence
+    /// modules without default export can be imported as well, including the files that
+    /// can't be inlined with `{ inline: true }`.
+    fn imported(&self, options: Option<&ExprOrSpread>) -> Imported {
+        let Some(options) = options else { return Imported::Namespace };
+        match self.option(options, "import") {
+            Some(Expr::Lit(Lit::Str(value))) if &*value.value == "default" => Imported::Default,
+            Some(Expr::Lit(Lit::Str(value))) => Imported::Named(value.value.to_string()),
+            _ => Imported::Namespace,
+        }
+    }
+
+    fn import_decl(&self, local: &str, src: &str, imported: &Imported) -> ModuleItem {
+        let local = ident(local);
+        let specifier = match imported {
+            Imported::Namespace => namespace_specifier(local),
+            Imported::Default => default_specifier(local),
+            Imported::Named(imported) => named_specifier(local, imported),
+        };
+        import_decl(src, vec![specifier])
+    }
+
+    /// `new URL("./foo.png", import.meta.url).href`, which is reported to porter as asset
+    /// and rewritten to the public url by swc-plugin-porter. The lazy ones are wrapped as
+    /// `() => Promise.resolve(url)` to keep the shape of lazy globs.
+    fn asset_url(&self, specifier: &str, eager: bool) -> Expr {
+        let href = member(new_url(specifier), "href");
+        if eager {
+            return href;
+        }
+        arrow(&[], call(member_chain(&["Promise", "resolve"]), vec![href]))
+    }
+
+    /// `{ inline: true }` inlines all of the JSON and text files, `{ inline: 4096 }` inlines
+    /// the ones no larger than 4096 bytes. The lazy ones are inlined as
+    /// `() => Promise.resolve(literal)` to keep the shape of lazy globs.
+    fn inline_limit(&self, n: &ExprOrSpread) -> Option<u64> {
+        match self.option(n, "inline") {
+            Some(Expr::Lit(Lit::Bool(value))) if value.value => Some(u64::MAX),
+            Some(Expr::Lit(Lit::Num(value))) => Some(value.value as u64),
+            _ => None,
+        }
+    }
+
+    /// Reads the file to be inlined, the errors are reported at the glob call.
+    fn read(&self, span: Span, specifier: &str) -> Option<Vec<u8>> {
+        fs::read(self.resolve(specifier))
+            .map_err(|err| error(span, &format!("failed to read {specifier}: {err}")))
+            .ok()
+    }
+
+    fn read_json(&self, span: Span, specifier: &str) -> Option<JsonValue> {
+        let source = String::from_utf8_lossy(&self.read(span, specifier)?).into_owned();
+        parse_json(&source, specifier)
+            .map_err(|err| error(span, &format!("invalid JSON: {err}")))
+            .ok()
+    }
+
+    /// The literal of the file being imported eagerly, either the parsed JSON or the text,
+    /// which saves the import of the file, in place of the module namespace or the default
+    /// export. The imported key of JSON is always inlined.
+    /// The files that can't be read or parsed, or miss the imported key, are reported and
+    /// left to be imported.
+    fn inline(&self, span: Span, specifier: &str, imported: &Imported, limit: Option<u64>) -> Option<Expr> {
+        let is_json = specifier.ends_with(".json");
+        let limit = match imported {
+            Imported::Namespace | Imported::Default => limit?,
+            Imported::Named(key) => {
+                if !is_json { return None; }
+                let json = self.read_json(span, specifier)?;
+                return match json.get(key) {
+                    Some(value) => Some(value.to_expr()),
+                    None => {
+                        error(span, &format!("{specifier} has no key `{key}` to be imported"));
+                        None
                     }
-                )
-            ],
-            src: Box::new(Str::from(src)),
-            type_only: false,
-            asserts: None,
-        }))
-    }
-
-    fn dynamic_import(&self, specifier: &str) -> Expr {
-        let callee = Callee::Import(Import { span: DUMMY_SP });
-        let mut args = Vec::new();
-        args.push(ExprOrSpread { spread: None, expr: Box::new(Expr::Lit(Lit::Str(Str::from(specifier)))) });
-        let expr = CallExpr { callee, args, span: DUMMY_SP, type_args: None };
-        let function = ArrowExpr {
-            span: DUMMY_SP,
-            params: Vec::new(),
-            body: Box::new(BlockStmtOrExpr::Expr(Box::new(Expr::Call(expr)))),
-            is_async: false,
-            is_generator: false,
-            type_params: None,
-            return_type: None,
+                };
+            }
         };
-        Expr::Arrow(function)
+        let extension = Path::new(specifier).extension().and_then(|ext| ext.to_str()).unwrap_or("");
+        if MODULE_EXTENSIONS.contains(&extension) {
+            return None;
+        }
+        let metadata = fs::metadata(self.resolve(specifier))
+            .map_err(|err| error(span, &format!("failed to read {specifier}: {err}")))
+            .ok()?;
+        if metadata.len() > limit {
+            return None;
+        }
+        if is_json {
+            return self.read_json(span, specifier).map(|json| json.to_expr());
+        }
+        // binary files are left as is
+        let text = String::from_utf8(self.read(span, specifier)?).ok()?;
+        Some(str_lit(&text))
+    }
+
+    fn resolve(&self, specifier: &str) -> PathBuf {
+        Path::new(&self.filepath).parent().unwrap().join(specifier)
+    }
+
+    /// Specifiers of the files matching the pattern, which are relative to the current file.
+    /// The pattern syntax is the same as node-glob, see [porter_swc_common::glob::glob].
+    fn specifiers(&self, pattern: &str) -> Vec<String> {
+        let base = Path::new(&self.filepath).parent().unwrap();
+        let mut specifiers = Vec::new();
+        for path in glob(base, pattern) {
+            let filename = path.strip_prefix(base).unwrap().to_str().unwrap();
+            let specifier = if filename.starts_with(".") {
+                filename.to_string()
+            } else {
+                format!("./{filename}")
+            };
+            specifiers.push(specifier);
+        }
+        specifiers
     }
 
     fn glob(&mut self, n: &CallExpr) -> Box<Expr> {
-        let ExprOrSpread { expr, .. } = n.args.get(0).expect("pattern required!");
+        let ExprOrSpread { expr, .. } = n.args.first().expect("pattern required!");
         let eager = match n.args.get(1) {
             Some(expr) => self.is_eager(expr),
             None => false,
         };
+        let imported = self.imported(n.args.get(1));
+        let inline = n.args.get(1).and_then(|expr| self.inline_limit(expr));
+        let url = n.args.get(1).is_some_and(|expr| self.is_url(expr));
         let mut props: Vec<PropOrSpread> = Vec::new();
         if let Expr::Lit(Lit::Str(specifier)) = &**expr {
-            let base = Path::new(&self.filepath).parent().unwrap();
-            let fullpath = base.join(specifier.value.to_string());
-            let pattern = fullpath.to_str().expect("pattern required!");
             let mut index = 0;
-            for entry in glob(&pattern).expect("Failed to read glob pattern") {
-                match entry {
-                    Ok(path) => {
-                        let filename = path.strip_prefix(base).unwrap().to_str().unwrap();
-                        let specifier = if filename.starts_with(".") {
-                            filename.to_string()
-                        } else {
-                            format!("./{filename}")
-                        };
-                        let value = if eager {
-                            let local = format!("__glob_{}_{}", self.glob_index, index);
-                            index += 1;
-                            self.module_items.push((local.to_string(), specifier.to_string()));
-                            Expr::Ident(Ident { span: DUMMY_SP, sym: local.into(), optional: false })
-                        } else {
-                            self.dynamic_import(&specifier)
-                        };
-                        let kv = KeyValueProp {
-                            key: PropName::Str(Str::from(specifier)),
-                            value: Box::new(value),
-                        };
-                        props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(kv))));
-                    },
-                    Err(e) => println!("{:?}", e),
-                }
+            for specifier in self.specifiers(&specifier.value) {
+                // lazy globs are inlined only if asked to, which defeats the code splitting
+                let inlined = if !url && (eager || inline.is_some()) {
+                    self.inline(n.span, &specifier, &imported, inline)
+                } else {
+                    None
+                };
+                let value = match inlined {
+                    _ if url => self.asset_url(&specifier, eager),
+                    Some(value) if eager => value,
+                    Some(value) => arrow(&[], call(member_chain(&["Promise", "resolve"]), vec![value])),
+                    None if eager => {
+                        let local = format!("__glob_{}_{}", self.glob_index, index);
+                        index += 1;
+                        self.module_items.push((local.to_string(), specifier.to_string(), imported.clone()));
+                        ident_expr(&local)
+                    }
+                    None => lazy_import(&specifier, imported.export_name()),
+                };
+                let kv = KeyValueProp {
+                    key: PropName::Str(Str::from(specifier)),
+                    value: Box::new(value),
+                };
+                props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(kv))));
             }
         }
+        if eager {
+            self.glob_index += 1;
+        }
         Box::new(Expr::Object(ObjectLit { span: n.span, props }))
     }
 }
@@ -151,44 +285,25 @@ impl VisitMut for GlobImport {
         }
     }
 
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Call(expr) = e {
+            if self.is_require_context(expr) {
+                if let Some(context) = self.context(expr) {
+                    *e = *context;
+                }
+            }
+        }
+    }
+
     fn visit_mut_module_items(&mut self, stmts: &mut Vec<ModuleItem>) {
         stmts.visit_mut_children_with(self);
 
-        let imports = self.module_items.iter().map(|(local, specifier)| {
-            self.import_decl(local, specifier)
+        let imports = self.module_items.iter().map(|(local, specifier, imported)| {
+            self.import_decl(local, specifier, imported)
         });
 
         prepend_stmts(stmts, imports.into_iter());
     }
 }
-
-/// An example plugin function with macro support.
-/// `plugin_transform` macro interop pointers into deserialized structs, as well
-/// as returning ptr back to host.
-///
-/// It is possible to opt out from macro by writing transform fn manually
-/// if plugin need to handle low-level ptr directly via
-/// `__transform_plugin_process_impl(
-///     ast_ptr: *const u8, ast_ptr_len: i32,
-///     unresolved_mark: u32, should_enable_comments_proxy: i32) ->
-///     i32 /*  0 for success, fail otherwise.
-///             Note this is only for internal pointer interop result,
-///             not actual transform result */`
-///
-/// This requires manual handling of serialization / deserialization from ptrs.
-/// Refer swc_plugin_macro to see how does it work internally.
-#[plugin_transform]
-pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
-    let cwd = metadata.get_context(&TransformPluginMetadataContextKind::Cwd).expect("cwd required");
-    let filename = metadata.get_context(&TransformPluginMetadataContextKind::Filename).expect("filename required");
-    // cwd is exposed to wasi at /cwd
-    // - https://github.com/kwonoj/swc/blob/main/crates/swc_plugin_runner/src/load_plugin.rs#L54
-    let filepath = filename.replace(&cwd, "/cwd");
-    program.fold_with(
-        &mut as_folder(GlobImport {
-            filepath,
-            module_items: Vec::new(),
-            glob_index: 0
-        })
-    )
-}
@@ -0,0 +1,2 @@
+const files = import.meta.glob('../../{i18n/{en,fr},data/a}/*.json');
+console.log(files);
@@ -0,0 +1,6 @@
+const files = {
+    "../../data/a/bar.json": ()=>import("../../data/a/bar.json"),
+    "../../i18n/en/messages.json": ()=>import("../../i18n/en/messages.json"),
+    "../../i18n/fr/messages.json": ()=>import("../../i18n/fr/messages.json")
+};
+console.log(files);
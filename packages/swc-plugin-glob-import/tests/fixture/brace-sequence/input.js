@@ -0,0 +1,3 @@
+const chapters = import.meta.glob('../../chapters/{1..3}.md');
+const files = import.meta.glob('../../data/{a..b}/*.json');
+console.log(chapters, files);
@@ -0,0 +1,10 @@
+const chapters = {
+    "../../chapters/1.md": ()=>import("../../chapters/1.md"),
+    "../../chapters/2.md": ()=>import("../../chapters/2.md"),
+    "../../chapters/3.md": ()=>import("../../chapters/3.md")
+};
+const files = {
+    "../../data/a/bar.json": ()=>import("../../data/a/bar.json"),
+    "../../data/b/baz.json": ()=>import("../../data/b/baz.json")
+};
+console.log(chapters, files);
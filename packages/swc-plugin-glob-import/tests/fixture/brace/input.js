@@ -0,0 +1,3 @@
+const messages = import.meta.glob('../../i18n/{en,zh}/*.json');
+const files = import.meta.glob('../../data/{a,b}/*.json', { eager: true });
+console.log(messages, files);
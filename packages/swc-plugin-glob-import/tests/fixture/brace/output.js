@@ -0,0 +1,11 @@
+import * as __glob_0_0 from "../../data/a/bar.json";
+import * as __glob_0_1 from "../../data/b/baz.json";
+const messages = {
+    "../../i18n/en/messages.json": ()=>import("../../i18n/en/messages.json"),
+    "../../i18n/zh/messages.json": ()=>import("../../i18n/zh/messages.json")
+};
+const files = {
+    "../../data/a/bar.json": __glob_0_0,
+    "../../data/b/baz.json": __glob_0_1
+};
+console.log(messages, files);
@@ -0,0 +1,4 @@
+const dir = '../../icons';
+const icons = require.context(dir, true, /\.svg$/);
+const files = require.context('../../icons', true, /(?<=arrows\/).+/);
+const eager = require.context('../../icons', true, /\.svg$/, 'weak');
@@ -0,0 +1,4 @@
+function importAll(context) {
+    return context.keys().map(context);
+}
+console.log(importAll(require.context('../../icons', false, /\.svg$/)));
@@ -0,0 +1,16 @@
+function importAll(context) {
+    return context.keys().map(context);
+}
+console.log(importAll(((modules)=>Object.assign((key)=>{
+        if (!Object.prototype.hasOwnProperty.call(modules, key)) throw Object.assign(new Error("Cannot find module '" + key + "'"), {
+            code: "MODULE_NOT_FOUND"
+        });
+        return modules[key]();
+    }, {
+        keys: ()=>Object.keys(modules),
+        resolve: (key)=>"../../icons" + key.slice(1),
+        id: "../../icons"
+    }))({
+    "./home.svg": ()=>require("../../icons/home.svg"),
+    "./user.svg": ()=>require("../../icons/user.svg")
+})));
@@ -0,0 +1,2 @@
+const messages = require.context('../../i18n/', true, /^\.\/(en|ZH)\//i, 'lazy');
+messages('./en/messages.json').then((m) => console.log(m.default));
@@ -0,0 +1,14 @@
+const messages = ((modules)=>Object.assign((key)=>{
+        if (!Object.prototype.hasOwnProperty.call(modules, key)) return Promise.reject(Object.assign(new Error("Cannot find module '" + key + "'"), {
+            code: "MODULE_NOT_FOUND"
+        }));
+        return modules[key]();
+    }, {
+        keys: ()=>Object.keys(modules),
+        resolve: (key)=>"../../i18n" + key.slice(1),
+        id: "../../i18n"
+    }))({
+    "./en/messages.json": ()=>import("../../i18n/en/messages.json"),
+    "./zh/messages.json": ()=>import("../../i18n/zh/messages.json")
+});
+messages('./en/messages.json').then((m)=>console.log(m.default));
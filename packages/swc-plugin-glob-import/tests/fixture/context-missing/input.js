@@ -0,0 +1,8 @@
+const icons = require.context('../../icons', false, /\.svg$/);
+try {
+  icons('./constructor');
+} catch (err) {
+  console.log(err.code);
+}
+const messages = require.context('../../i18n/', true, /\.json$/, 'lazy');
+messages('./de/messages.json').catch((err) => console.log(err.code));
@@ -0,0 +1,33 @@
+const icons = ((modules)=>Object.assign((key)=>{
+        if (!Object.prototype.hasOwnProperty.call(modules, key)) throw Object.assign(new Error("Cannot find module '" + key + "'"), {
+            code: "MODULE_NOT_FOUND"
+        });
+        return modules[key]();
+    }, {
+        keys: ()=>Object.keys(modules),
+        resolve: (key)=>"../../icons" + key.slice(1),
+        id: "../../icons"
+    }))({
+    "./home.svg": ()=>require("../../icons/home.svg"),
+    "./user.svg": ()=>require("../../icons/user.svg")
+});
+try {
+    icons('./constructor');
+} catch (err) {
+    console.log(err.code);
+}
+const messages = ((modules)=>Object.assign((key)=>{
+        if (!Object.prototype.hasOwnProperty.call(modules, key)) return Promise.reject(Object.assign(new Error("Cannot find module '" + key + "'"), {
+            code: "MODULE_NOT_FOUND"
+        }));
+        return modules[key]();
+    }, {
+        keys: ()=>Object.keys(modules),
+        resolve: (key)=>"../../i18n" + key.slice(1),
+        id: "../../i18n"
+    }))({
+    "./en/messages.json": ()=>import("../../i18n/en/messages.json"),
+    "./fr/messages.json": ()=>import("../../i18n/fr/messages.json"),
+    "./zh/messages.json": ()=>import("../../i18n/zh/messages.json")
+});
+messages('./de/messages.json').catch((err)=>console.log(err.code));
@@ -0,0 +1,4 @@
+const icons = require.context('../../icons', true, /\.svg$/);
+icons.keys().forEach((key) => console.log(key, icons.resolve(key), icons(key)));
+const modules = require.context('../../modules');
+console.log(modules.keys());
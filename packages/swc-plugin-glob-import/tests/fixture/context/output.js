@@ -0,0 +1,30 @@
+const icons = ((modules)=>Object.assign((key)=>{
+        if (!Object.prototype.hasOwnProperty.call(modules, key)) throw Object.assign(new Error("Cannot find module '" + key + "'"), {
+            code: "MODULE_NOT_FOUND"
+        });
+        return modules[key]();
+    }, {
+        keys: ()=>Object.keys(modules),
+        resolve: (key)=>"../../icons" + key.slice(1),
+        id: "../../icons"
+    }))({
+    "./arrows/left.svg": ()=>require("../../icons/arrows/left.svg"),
+    "./arrows/right.svg": ()=>require("../../icons/arrows/right.svg"),
+    "./home.svg": ()=>require("../../icons/home.svg"),
+    "./user.svg": ()=>require("../../icons/user.svg")
+});
+icons.keys().forEach((key)=>console.log(key, icons.resolve(key), icons(key)));
+const modules = ((modules)=>Object.assign((key)=>{
+        if (!Object.prototype.hasOwnProperty.call(modules, key)) throw Object.assign(new Error("Cannot find module '" + key + "'"), {
+            code: "MODULE_NOT_FOUND"
+        });
+        return modules[key]();
+    }, {
+        keys: ()=>Object.keys(modules),
+        resolve: (key)=>"../../modules" + key.slice(1),
+        id: "../../modules"
+    }))({
+    "./button.js": ()=>require("../../modules/button.js"),
+    "./dialog.js": ()=>require("../../modules/dialog.js")
+});
+console.log(modules.keys());
@@ -0,0 +1,4 @@
+const renders = import.meta.glob('../../modules/*.js', { eager: true, import: 'default' });
+const lazy = import.meta.glob('../../modules/*.js', { import: 'default' });
+const names = import.meta.glob('../../modules/*.js', { eager: true, import: 'name' });
+console.log(renders, lazy, names);
@@ -0,0 +1,17 @@
+import __glob_0_0 from "../../modules/button.js";
+import __glob_0_1 from "../../modules/dialog.js";
+import { name as __glob_1_0 } from "../../modules/button.js";
+import { name as __glob_1_1 } from "../../modules/dialog.js";
+const renders = {
+    "../../modules/button.js": __glob_0_0,
+    "../../modules/dialog.js": __glob_0_1
+};
+const lazy = {
+    "../../modules/button.js": ()=>import("../../modules/button.js").then((m)=>m.default),
+    "../../modules/dialog.js": ()=>import("../../modules/dialog.js").then((m)=>m.default)
+};
+const names = {
+    "../../modules/button.js": __glob_1_0,
+    "../../modules/dialog.js": __glob_1_1
+};
+console.log(renders, lazy, names);
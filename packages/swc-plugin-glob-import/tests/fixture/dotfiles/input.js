@@ -0,0 +1,4 @@
+// the files starting with `.` are matched by the patterns starting with `.` only
+const chapters = import.meta.glob('../../chapters/*.md');
+const drafts = import.meta.glob('../../chapters/.*.md');
+console.log(chapters, drafts);
@@ -0,0 +1,11 @@
+// the files starting with `.` are matched by the patterns starting with `.` only
+const chapters = {
+    "../../chapters/1.md": ()=>import("../../chapters/1.md"),
+    "../../chapters/10.md": ()=>import("../../chapters/10.md"),
+    "../../chapters/2.md": ()=>import("../../chapters/2.md"),
+    "../../chapters/3.md": ()=>import("../../chapters/3.md")
+};
+const drafts = {
+    "../../chapters/.draft.md": ()=>import("../../chapters/.draft.md")
+};
+console.log(chapters, drafts);
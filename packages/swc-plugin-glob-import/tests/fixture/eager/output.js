@@ -1,7 +1,6 @@
-import __glob_0_0 from "../../data/a/bar.json";
-import __glob_0_1 from "../../data/b/baz.json";
-import __glob_0_2 from "../../data/foo.json";
-
+import * as __glob_0_0 from "../../data/a/bar.json";
+import * as __glob_0_1 from "../../data/b/baz.json";
+import * as __glob_0_2 from "../../data/foo.json";
 const files = {
     "../../data/a/bar.json": __glob_0_0,
     "../../data/b/baz.json": __glob_0_1,
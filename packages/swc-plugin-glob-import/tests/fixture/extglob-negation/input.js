@@ -0,0 +1,3 @@
+const messages = import.meta.glob('../../i18n/!(en)/*.json');
+const data = import.meta.glob('../../locales/!(*.js)');
+console.log(messages, data);
@@ -0,0 +1,10 @@
+const messages = {
+    "../../i18n/fr/messages.json": ()=>import("../../i18n/fr/messages.json"),
+    "../../i18n/zh/messages.json": ()=>import("../../i18n/zh/messages.json")
+};
+const data = {
+    "../../locales/en.json": ()=>import("../../locales/en.json"),
+    "../../locales/greeting.txt": ()=>import("../../locales/greeting.txt"),
+    "../../locales/zh.json": ()=>import("../../locales/zh.json")
+};
+console.log(messages, data);
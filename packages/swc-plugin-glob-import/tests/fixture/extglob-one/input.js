@@ -0,0 +1,2 @@
+const modules = import.meta.glob('../../modules/@(button|dialog).js');
+console.log(modules);
@@ -0,0 +1,5 @@
+const modules = {
+    "../../modules/button.js": ()=>import("../../modules/button.js"),
+    "../../modules/dialog.js": ()=>import("../../modules/dialog.js")
+};
+console.log(modules);
@@ -0,0 +1,2 @@
+const chapters = import.meta.glob('../../chapters/1?(0).md');
+console.log(chapters);
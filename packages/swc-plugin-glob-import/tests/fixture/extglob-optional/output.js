@@ -0,0 +1,5 @@
+const chapters = {
+    "../../chapters/1.md": ()=>import("../../chapters/1.md"),
+    "../../chapters/10.md": ()=>import("../../chapters/10.md")
+};
+console.log(chapters);
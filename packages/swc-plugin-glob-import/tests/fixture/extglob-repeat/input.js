@@ -0,0 +1,3 @@
+const some = import.meta.glob('../../chapters/+(1|0).md');
+const any = import.meta.glob('../../chapters/*(1|2|0).md');
+console.log(some, any);
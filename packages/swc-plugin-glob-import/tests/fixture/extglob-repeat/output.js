@@ -0,0 +1,10 @@
+const some = {
+    "../../chapters/1.md": ()=>import("../../chapters/1.md"),
+    "../../chapters/10.md": ()=>import("../../chapters/10.md")
+};
+const any = {
+    "../../chapters/1.md": ()=>import("../../chapters/1.md"),
+    "../../chapters/10.md": ()=>import("../../chapters/10.md"),
+    "../../chapters/2.md": ()=>import("../../chapters/2.md")
+};
+console.log(some, any);
@@ -0,0 +1,2 @@
+const dates = import.meta.glob('../../locales/*.json', { eager: true, import: 'dates' });
+console.log(dates);
@@ -0,0 +1,9 @@
+import { dates as __glob_0_0 } from "../../locales/zh.json";
+const dates = {
+    "../../locales/en.json": [
+        "Sunday",
+        "Monday"
+    ],
+    "../../locales/zh.json": __glob_0_0
+};
+console.log(dates);
@@ -0,0 +1,5 @@
+const titles = import.meta.glob('../../locales/*.json', { eager: true, import: 'title' });
+const messages = import.meta.glob('../../locales/*.json', { import: 'messages' });
+const setups = import.meta.glob('../../locales/*.js', { eager: true, import: 'setup' });
+const modules = import.meta.glob('../../locales/*.js', { eager: true, import: 'default' });
+console.log(titles, messages, setups, modules);
@@ -0,0 +1,17 @@
+import { setup as __glob_1_0 } from "../../locales/setup.js";
+import __glob_2_0 from "../../locales/setup.js";
+const titles = {
+    "../../locales/en.json": "Hello",
+    "../../locales/zh.json": "你好"
+};
+const messages = {
+    "../../locales/en.json": ()=>import("../../locales/en.json").then((m)=>m.messages),
+    "../../locales/zh.json": ()=>import("../../locales/zh.json").then((m)=>m.messages)
+};
+const setups = {
+    "../../locales/setup.js": __glob_1_0
+};
+const modules = {
+    "../../locales/setup.js": __glob_2_0
+};
+console.log(titles, messages, setups, modules);
@@ -0,0 +1,3 @@
+const data = import.meta.glob('../../broken/*.json', { eager: true, inline: true });
+const names = import.meta.glob('../../broken/*.json', { eager: true, import: 'name' });
+console.log(data, names);
@@ -0,0 +1,9 @@
+import * as __glob_0_0 from "../../broken/invalid.json";
+import { name as __glob_1_0 } from "../../broken/invalid.json";
+const data = {
+    "../../broken/invalid.json": __glob_0_0
+};
+const names = {
+    "../../broken/invalid.json": __glob_1_0
+};
+console.log(data, names);
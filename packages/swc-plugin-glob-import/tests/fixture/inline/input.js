@@ -0,0 +1,5 @@
+const locales = import.meta.glob('../../locales/*', { eager: true, inline: true });
+const small = import.meta.glob('../../locales/*.json', { eager: true, inline: 80 });
+const lazy = import.meta.glob('../../locales/*.json', { inline: true });
+const lazySmall = import.meta.glob('../../locales/*', { inline: 80 });
+console.log(locales, small, lazy, lazySmall);
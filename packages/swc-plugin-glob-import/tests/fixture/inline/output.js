@@ -0,0 +1,61 @@
+import * as __glob_0_0 from "../../locales/setup.js";
+import * as __glob_1_0 from "../../locales/en.json";
+const locales = {
+    "../../locales/en.json": {
+        title: "Hello",
+        messages: {
+            greeting: "Hello, %s!"
+        },
+        dates: [
+            "Sunday",
+            "Monday"
+        ]
+    },
+    "../../locales/greeting.txt": "Hello `world`, ${name}!\n",
+    "../../locales/setup.js": __glob_0_0,
+    "../../locales/zh.json": {
+        title: "你好",
+        messages: {
+            greeting: "%s，你好！"
+        }
+    }
+};
+const small = {
+    "../../locales/en.json": __glob_1_0,
+    "../../locales/zh.json": {
+        title: "你好",
+        messages: {
+            greeting: "%s，你好！"
+        }
+    }
+};
+const lazy = {
+    "../../locales/en.json": ()=>Promise.resolve({
+            title: "Hello",
+            messages: {
+                greeting: "Hello, %s!"
+            },
+            dates: [
+                "Sunday",
+                "Monday"
+            ]
+        }),
+    "../../locales/zh.json": ()=>Promise.resolve({
+            title: "你好",
+            messages: {
+                greeting: "%s，你好！"
+            }
+        })
+};
+const lazySmall = {
+    "../../locales/en.json": ()=>import("../../locales/en.json"),
+    "../../locales/greeting.txt": ()=>Promise.resolve("Hello `world`, ${name}!\n"),
+    "../../locales/setup.js": ()=>import("../../locales/setup.js"),
+    "../../locales/zh.json": ()=>Promise.resolve({
+            title: "你好",
+            messages: {
+                greeting: "%s，你好！"
+            }
+        })
+};
+console.log(locales, small, lazy, lazySmall);
@@ -0,0 +1,4 @@
+// the modules have no default export, or not all of them do
+const modules = import.meta.glob('../../modules/*.js', { eager: true });
+const lazy = import.meta.glob('../../modules/*.js');
+console.log(modules, lazy);
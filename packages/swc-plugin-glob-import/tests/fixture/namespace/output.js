@@ -0,0 +1,12 @@
+// the modules have no default export, or not all of them do
+import * as __glob_0_0 from "../../modules/button.js";
+import * as __glob_0_1 from "../../modules/dialog.js";
+const modules = {
+    "../../modules/button.js": __glob_0_0,
+    "../../modules/dialog.js": __glob_0_1
+};
+const lazy = {
+    "../../modules/button.js": ()=>import("../../modules/button.js"),
+    "../../modules/dialog.js": ()=>import("../../modules/dialog.js")
+};
+console.log(modules, lazy);
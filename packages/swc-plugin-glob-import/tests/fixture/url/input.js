@@ -0,0 +1,3 @@
+const images = import.meta.glob('../../images/*', { eager: true, as: 'url' });
+const lazy = import.meta.glob('../../images/*.svg', { as: 'url' });
+console.log(images, lazy);
@@ -0,0 +1,8 @@
+const images = {
+    "../../images/a.png": new URL("../../images/a.png", import.meta.url).href,
+    "../../images/b.svg": new URL("../../images/b.svg", import.meta.url).href
+};
+const lazy = {
+    "../../images/b.svg": ()=>Promise.resolve(new URL("../../images/b.svg", import.meta.url).href)
+};
+console.log(images, lazy);
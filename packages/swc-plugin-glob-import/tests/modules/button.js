@@ -0,0 +1,2 @@
+export const name = 'button';
+export function render() {}
@@ -0,0 +1,2 @@
+export const name = 'dialog';
+export default function render() {}
@@ -0,0 +1,13 @@
+use glob_import::glob_import_transform;
+use porter_swc_common::config::plugin_filepath;
+use swc_core::{
+    ecma::{ast::Program, visit::{as_folder, FoldWith}},
+    plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
+};
+
+/// Replaces `import.meta.glob()` and `require.context()` with the matched files, see
+/// [glob_import_transform].
+#[plugin_transform]
+pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
+    program.fold_with(&mut as_folder(glob_import_transform(plugin_filepath(&metadata))))
+}
@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use swc_core::{
+    common::{
+        comments::{Comment, CommentKind, Comments},
+        BytePos, Spanned, DUMMY_SP,
+    },
+    ecma::{
+        ast::{
+            BinExpr, BinaryOp, BindingIdent, CallExpr, Callee, CondExpr, ExportAll, ExportSpecifier,
+            Expr, ExprOrSpread, Ident, IfStmt, ImportDecl, ImportSpecifier, KeyValueProp, Lit,
+            MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, Module, ModuleDecl, ModuleExportName,
+            NamedExport, PropName, PropOrSpread, Prop, Script, TsEntityName,
+            TsExprWithTypeArgs,
+        },
+        visit::{Visit, VisitMut, VisitWith},
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ImportName {
+    pub export: String,
+    pub local: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct Import {
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<ImportName>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regexp: Option<ContextRegExp>,
+    #[serde(flatten)]
+    pub glob: GlobOptions,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct DynamicImport {
+    pub source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regexp: Option<ContextRegExp>,
+    #[serde(flatten)]
+    pub glob: GlobOptions,
+}
+
+/// The regexp of `require.context()`, which the files matched by the pattern are tested
+/// against with their paths relative to the directory, e.g. `./a.svg`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ContextRegExp {
+    pub source: String,
+    pub flags: String,
+}
+
+/// The options of `import.meta.glob()` that decide which of the matched files are inlined
+/// by swc-plugin-glob-import rather than imported.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+pub struct GlobOptions {
+    /// The size limit of `{ inline: 4096 }`, `{ inline: true }` has no limit.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline: Option<u64>,
+    /// `{ import: 'foo' }`, the key is always inlined from the JSON files of eager globs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub import: Option<String>,
+}
+
+/// Same shape as the properties of ImportVisitor in porter/src/import_visitor.ts
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportAnalysis {
+    pub imports: Vec<Import>,
+    pub dynamic_imports: Vec<DynamicImport>,
+    /// Locals that are referenced in type positions only.
+    pub type_imports: Vec<String>,
+    #[serde(rename = "__esModule")]
+    pub es_module: bool,
+}
+
+/// Collects imports of a module, which is a port of porter's ImportVisitor.
+#[derive(Default)]
+pub struct ImportVisitor {
+    result: ImportAnalysis,
+    /// References in value positions, which keep the imports from being pruned.
+    identifiers: HashMap<String, usize>,
+}
+
+/// Visits the module or script and returns the collected imports.
+pub fn analyze_imports<N>(n: &N) -> ImportAnalysis
+where
+    N: VisitWith<ImportVisitor>,
+{
+    let mut visitor = ImportVisitor::default();
+    n.visit_with(&mut visitor);
+    visitor.finish()
+}
+
+fn is_declaration_file(source: &str) -> bool {
+    source.ends_with(".d.ts")
+}
+
+fn export_name(name: &ModuleExportName) -> String {
+    match name {
+        ModuleExportName::Ident(id) => id.sym.to_string(),
+        ModuleExportName::Str(s) => s.value.to_string(),
+    }
+}
+
+/// Evaluates `if ('production' === 'production')` and the likes, which are the results
+/// of `process.env.NODE_ENV` being replaced.
+fn eval_test(expr: &Expr) -> Option<bool> {
+    match expr {
+        Expr::Lit(Lit::Bool(b)) => Some(b.value),
+        Expr::Bin(BinExpr { op, left, right, .. }) => {
+            let result = match (&**left, &**right) {
+                (Expr::Lit(Lit::Str(a)), Expr::Lit(Lit::Str(b))) => a.value == b.value,
+                (Expr::Lit(Lit::Bool(a)), Expr::Lit(Lit::Bool(b))) => a.value == b.value,
+                _ => return None,
+            };
+            match op {
+                BinaryOp::EqEq | BinaryOp::EqEqEq => Some(result),
+                BinaryOp::NotEq | BinaryOp::NotEqEq => Some(!result),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// `import.meta.glob('./data/*.json', { eager: true })`, the ones with `{ as: 'url' }`
+/// are assets rather than imports.
+fn glob_import(args: &[ExprOrSpread]) -> Option<(String, bool, GlobOptions)> {
+    let Expr::Lit(Lit::Str(pattern)) = &*args.first()?.expr else { return None };
+    let mut eager = false;
+    let mut options = GlobOptions::default();
+    if let Some(Expr::Object(object)) = args.get(1).map(|arg| &*arg.expr) {
+        for prop in object.props.iter() {
+            if let PropOrSpread::Prop(prop) = prop {
+                if let Prop::KeyValue(KeyValueProp { key, value }) = &**prop {
+                    let key = match key {
+                        PropName::Ident(id) => &*id.sym,
+                        PropName::Str(s) => &*s.value,
+                        _ => continue,
+                    };
+                    match (key, &**value) {
+                        ("eager", Expr::Lit(Lit::Bool(value))) => eager = value.value,
+                        ("as", Expr::Lit(Lit::Str(value))) if &*value.value == "url" => return None,
+                        ("inline", Expr::Lit(Lit::Bool(value))) if value.value => options.inline = Some(u64::MAX),
+                        ("inline", Expr::Lit(Lit::Num(value))) => options.inline = Some(value.value as u64),
+                        ("import", Expr::Lit(Lit::Str(value))) => options.import = Some(value.value.to_string()),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    Some((pattern.value.to_string(), eager, options))
+}
+
+/// `require.context('./icons', true, /\.svg$/)`, which is the directory, the pattern that
+/// matches the files in it, and whether the context is lazy. The defaults are the same as
+/// webpack's, the ones that swc-plugin-glob-import can't transform are skipped.
+fn require_context(args: &[ExprOrSpread]) -> Option<(String, String, ContextRegExp, bool)> {
+    let mut args = args.iter().map(|arg| &*arg.expr);
+    let Some(Expr::Lit(Lit::Str(directory))) = args.next() else { return None };
+    let directory = directory.value.trim_end_matches('/').to_string();
+    let recursive = match args.next() {
+        None => true,
+        Some(Expr::Lit(Lit::Bool(value))) => value.value,
+        Some(_) => return None,
+    };
+    let regexp = match args.next() {
+        None => ContextRegExp { source: r"^\./.*$".to_string(), flags: String::new() },
+        Some(Expr::Lit(Lit::Regex(lit))) => ContextRegExp { source: lit.exp.to_string(), flags: lit.flags.to_string() },
+        Some(_) => return None,
+    };
+    let lazy = match args.next() {
+        None => false,
+        Some(Expr::Lit(Lit::Str(mode))) if &*mode.value == "sync" => false,
+        Some(Expr::Lit(Lit::Str(mode))) if &*mode.value == "lazy" => true,
+        Some(_) => return None,
+    };
+    let pattern = if recursive { format!("{directory}/**/*") } else { format!("{directory}/*") };
+    Some((directory, pattern, regexp, lazy))
+}
+
+impl ImportVisitor {
+    fn add_type_identifier(&mut self, name: &TsEntityName) {
+        let mut name = name;
+        while let TsEntityName::TsQualifiedName(qualified) = name {
+            name = &qualified.left;
+        }
+        if let TsEntityName::Ident(id) = name {
+            self.result.type_imports.push(id.sym.to_string());
+        }
+    }
+
+    /// Prunes imports that are used as types only, see ImportVisitor.visitProgram
+    pub fn finish(self) -> ImportAnalysis {
+        let ImportVisitor { mut result, identifiers, .. } = self;
+
+        let mut type_imports = Vec::new();
+        for local in result.type_imports.drain(..) {
+            if !identifiers.contains_key(&local) && !type_imports.contains(&local) {
+                type_imports.push(local);
+            }
+        }
+
+        result.imports.retain_mut(|entry| {
+            let Some(names) = &mut entry.names else { return true };
+            if names.is_empty() { return true; }
+            names.retain(|name| !type_imports.contains(&name.local));
+            !names.is_empty()
+        });
+        result.type_imports = type_imports;
+        result
+    }
+}
+
+impl Visit for ImportVisitor {
+    fn visit_module_decl(&mut self, n: &ModuleDecl) {
+        self.result.es_module = true;
+        n.visit_children_with(self);
+    }
+
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        if is_declaration_file(&n.src.value) || n.type_only { return; }
+
+        let names = n.specifiers.iter().filter_map(|specifier| {
+            match specifier {
+                ImportSpecifier::Default(s) => Some(ImportName {
+                    export: "default".to_string(),
+                    local: s.local.sym.to_string(),
+                }),
+                ImportSpecifier::Named(s) if !s.is_type_only => Some(ImportName {
+                    export: s.imported.as_ref().map(export_name).unwrap_or_else(|| s.local.sym.to_string()),
+                    local: s.local.sym.to_string(),
+                }),
+                ImportSpecifier::Namespace(s) => Some(ImportName {
+                    export: "*".to_string(),
+                    local: s.local.sym.to_string(),
+                }),
+                _ => None,
+            }
+        }).collect();
+
+        self.result.imports.push(Import {
+            source: n.src.value.to_string(),
+            names: Some(names),
+            ..Default::default()
+        });
+    }
+
+    fn visit_export_all(&mut self, n: &ExportAll) {
+        if is_declaration_file(&n.src.value) || n.type_only { return; }
+        self.result.imports.push(Import {
+            source: n.src.value.to_string(),
+            names: Some(Vec::new()),
+            ..Default::default()
+        });
+    }
+
+    fn visit_named_export(&mut self, n: &NamedExport) {
+        let Some(src) = &n.src else {
+            n.visit_children_with(self);
+            return;
+        };
+        if is_declaration_file(&src.value) || n.type_only { return; }
+        let names = n.specifiers.iter().filter_map(|specifier| match specifier {
+            ExportSpecifier::Named(s) if !s.is_type_only => Some(ImportName {
+                export: export_name(&s.orig),
+                local: export_name(s.exported.as_ref().unwrap_or(&s.orig)),
+            }),
+            _ => None,
+        }).collect();
+        self.result.imports.push(Import {
+            source: src.value.to_string(),
+            names: Some(names),
+            ..Default::default()
+        });
+    }
+
+    fn visit_call_expr(&mut self, n: &CallExpr) {
+        let source = match n.args.first().map(|arg| &*arg.expr) {
+            Some(Expr::Lit(Lit::Str(s))) => s.value.to_string(),
+            _ => return n.visit_children_with(self),
+        };
+
+        match &n.callee {
+            // import('./foo')
+            Callee::Import(_) => {
+                self.result.es_module = true;
+                self.result.dynamic_imports.push(DynamicImport { source, ..Default::default() });
+                return;
+            }
+            Callee::Expr(callee) => match &**callee {
+                // require('./foo')
+                Expr::Ident(id) if &*id.sym == "require" => {
+                    self.result.imports.push(Import { source, ..Default::default() });
+                    return;
+                }
+                Expr::Member(MemberExpr { obj, prop: MemberProp::Ident(prop), .. }) => {
+                    match &**obj {
+                        // require.async('./foo')
+                        Expr::Ident(id) if &*id.sym == "require" && &*prop.sym == "async" => {
+                            self.result.dynamic_imports.push(DynamicImport { source, ..Default::default() });
+                            return;
+                        }
+                        // require.context('./icons', true, /\.svg$/)
+                        Expr::Ident(id) if &*id.sym == "require" && &*prop.sym == "context" => {
+                            if let Some((source, pattern, regexp, lazy)) = require_context(&n.args) {
+                                let (pattern, regexp) = (Some(pattern), Some(regexp));
+                                if lazy {
+                                    self.result.dynamic_imports.push(DynamicImport { source, pattern, regexp, ..Default::default() });
+                                } else {
+                                    self.result.imports.push(Import { source, pattern, regexp, ..Default::default() });
+                                }
+                            }
+                            return;
+                        }
+                        // import.meta.glob('./data/*.json')
+                        Expr::MetaProp(MetaPropExpr { kind: MetaPropKind::ImportMeta, .. }) if &*prop.sym == "glob" => {
+                            if let Some((pattern, eager, glob)) = glob_import(&n.args) {
+                                let source = pattern.clone();
+                                if eager {
+                                    self.result.imports.push(Import { source, pattern: Some(pattern), glob, ..Default::default() });
+                                } else {
+                                    self.result.dynamic_imports.push(DynamicImport { source, pattern: Some(pattern), glob, ..Default::default() });
+                                }
+                            }
+                            return;
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_if_stmt(&mut self, n: &IfStmt) {
+        match eval_test(&n.test) {
+            Some(true) => n.cons.visit_with(self),
+            Some(false) => n.alt.visit_with(self),
+            None => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_cond_expr(&mut self, n: &CondExpr) {
+        match eval_test(&n.test) {
+            Some(true) => n.cons.visit_with(self),
+            Some(false) => n.alt.visit_with(self),
+            None => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_ts_entity_name(&mut self, n: &TsEntityName) {
+        self.add_type_identifier(n);
+    }
+
+    /// `class Foo implements Bar {}` or `interface Foo extends Bar {}`
+    fn visit_ts_expr_with_type_args(&mut self, n: &TsExprWithTypeArgs) {
+        let mut expr = &*n.expr;
+        while let Expr::Member(MemberExpr { obj, .. }) = expr {
+            expr = obj;
+        }
+        if let Expr::Ident(id) = expr {
+            self.add_type_identifier(&TsEntityName::Ident(id.clone()));
+        }
+        n.type_args.visit_with(self);
+    }
+
+    fn visit_binding_ident(&mut self, n: &BindingIdent) {
+        n.type_ann.visit_with(self);
+    }
+
+    fn visit_member_prop(&mut self, n: &MemberProp) {
+        if let MemberProp::Computed(prop) = n {
+            prop.visit_with(self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName) {
+        if let PropName::Computed(prop) = n {
+            prop.visit_with(self);
+        }
+    }
+
+    fn visit_ident(&mut self, n: &Ident) {
+        *self.identifiers.entry(n.sym.to_string()).or_default() += 1;
+    }
+}
+
+/// Analyzes imports without changing the program, the result is attached as a trailing
+/// comment for porter to pick up since the wasm plugin can only return the program:
+///
+/// ```js
+/// //# porterImportAnalysis={"imports":[...],"dynamicImports":[],"typeImports":[],"__esModule":true}
+/// ```
+pub fn import_analysis_transform<C>(comments: C) -> impl VisitMut
+where
+    C: Comments,
+{
+    ImportAnalysisReporter { comments }
+}
+
+struct ImportAnalysisReporter<C>
+where
+    C: Comments,
+{
+    comments: C,
+}
+
+impl<C> ImportAnalysisReporter<C>
+where
+    C: Comments,
+{
+    fn report(&self, result: &ImportAnalysis, pos: BytePos) {
+        let json = serde_json::to_string(result).expect("failed to serialize import analysis");
+        self.comments.add_trailing(pos, Comment {
+            kind: CommentKind::Line,
+            span: DUMMY_SP,
+            text: format!("# porterImportAnalysis={json}").into(),
+        });
+    }
+}
+
+impl<C> VisitMut for ImportAnalysisReporter<C>
+where
+    C: Comments,
+{
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        self.report(&analyze_imports(n), n.span().hi);
+    }
+
+    fn visit_mut_script(&mut self, n: &mut Script) {
+        self.report(&analyze_imports(n), n.span().hi);
+    }
+}
+
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Config {
+    /// Drops imports that are referenced in type positions only before analyzing,
+    /// see [crate::type_import_elision].
+    pub elide_type_imports: bool,
+}
@@ -0,0 +1,4 @@
+pub use self::import_analysis::*;
+pub use self::type_elision::*;
+pub mod import_analysis;
+pub mod type_elision;
@@ -0,0 +1,164 @@
+use std::collections::HashSet;
+
+use swc_core::{
+    common::util::take::Take,
+    ecma::{
+        ast::{
+            ExportSpecifier, Expr, Id, Ident, ImportDecl, ImportSpecifier, MemberExpr, MemberProp, Module,
+            ModuleDecl, ModuleItem, PropName, TsEntityName, TsExprWithTypeArgs, TsInterfaceDecl, TsModuleDecl,
+            TsType, TsTypeAliasDecl, TsTypeAnn, TsTypeParamDecl, TsTypeParamInstantiation,
+        },
+        visit::{Visit, VisitMut, VisitWith},
+    },
+};
+
+/// Drops imports and import specifiers that are referenced in type positions only,
+/// the ones that aren't referenced at all are kept for the side effects. It requires the
+/// program to be resolved with `resolver(unresolved_mark, top_level_mark, true)` first so
+/// that shadowed names can be told apart by their syntax context.
+///
+/// Like `isolatedModules`, it works on one file at a time, hence `export { Foo }` is
+/// considered as value usage of `Foo` since it can not be known whether `Foo` is a type.
+pub fn type_import_elision() -> impl VisitMut {
+    TypeImportElision
+}
+
+struct TypeImportElision;
+
+/// Collects identifiers referenced in value positions.
+#[derive(Default)]
+struct ValueUsage {
+    ids: HashSet<Id>,
+}
+
+impl Visit for ValueUsage {
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_ts_type(&mut self, _: &TsType) {}
+
+    fn visit_ts_type_ann(&mut self, _: &TsTypeAnn) {}
+
+    fn visit_ts_type_param_decl(&mut self, _: &TsTypeParamDecl) {}
+
+    fn visit_ts_type_param_instantiation(&mut self, _: &TsTypeParamInstantiation) {}
+
+    fn visit_ts_interface_decl(&mut self, _: &TsInterfaceDecl) {}
+
+    fn visit_ts_type_alias_decl(&mut self, _: &TsTypeAliasDecl) {}
+
+    /// `class Foo implements Bar {}`
+    fn visit_ts_expr_with_type_args(&mut self, _: &TsExprWithTypeArgs) {}
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+        if !n.declare {
+            n.visit_children_with(self);
+        }
+    }
+
+    fn visit_member_prop(&mut self, n: &MemberProp) {
+        if let MemberProp::Computed(prop) = n {
+            prop.visit_with(self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName) {
+        if let PropName::Computed(prop) = n {
+            prop.visit_with(self);
+        }
+    }
+
+    fn visit_ident(&mut self, n: &Ident) {
+        self.ids.insert(n.to_id());
+    }
+}
+
+/// Collects identifiers referenced in type positions, e.g. `Foo` in `let foo: Foo.Bar`.
+#[derive(Default)]
+struct TypeUsage {
+    ids: HashSet<Id>,
+}
+
+impl Visit for TypeUsage {
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_ts_entity_name(&mut self, n: &TsEntityName) {
+        let mut name = n;
+        while let TsEntityName::TsQualifiedName(qualified) = name {
+            name = &qualified.left;
+        }
+        if let TsEntityName::Ident(id) = name {
+            self.ids.insert(id.to_id());
+        }
+    }
+
+    /// `class Foo implements Bar {}` or `interface Foo extends Bar {}`
+    fn visit_ts_expr_with_type_args(&mut self, n: &TsExprWithTypeArgs) {
+        let mut expr = &*n.expr;
+        while let Expr::Member(MemberExpr { obj, .. }) = expr {
+            expr = obj;
+        }
+        if let Expr::Ident(id) = expr {
+            self.ids.insert(id.to_id());
+        }
+        n.type_args.visit_with(self);
+    }
+}
+
+fn is_declaration_file(source: &str) -> bool {
+    source.ends_with(".d.ts")
+}
+
+impl TypeImportElision {
+    /// Returns false if the whole declaration should be dropped.
+    fn elide_import(&self, decl: &mut ImportDecl, values: &ValueUsage, types: &TypeUsage) -> bool {
+        if decl.type_only || is_declaration_file(&decl.src.value) { return false; }
+        // import './foo';
+        if decl.specifiers.is_empty() { return true; }
+        decl.specifiers.retain(|specifier| {
+            let local = match specifier {
+                ImportSpecifier::Named(s) if s.is_type_only => return false,
+                ImportSpecifier::Named(s) => &s.local,
+                ImportSpecifier::Default(s) => &s.local,
+                ImportSpecifier::Namespace(s) => &s.local,
+            };
+            let id = local.to_id();
+            values.ids.contains(&id) || !types.ids.contains(&id)
+        });
+        !decl.specifiers.is_empty()
+    }
+
+    fn elide_export(&self, decl: &mut ModuleDecl) -> bool {
+        match decl {
+            ModuleDecl::ExportAll(n) => !n.type_only && !is_declaration_file(&n.src.value),
+            ModuleDecl::ExportNamed(n) => {
+                if n.type_only { return false; }
+                if let Some(src) = &n.src {
+                    if is_declaration_file(&src.value) { return false; }
+                }
+                let len = n.specifiers.len();
+                n.specifiers.retain(|specifier| !matches!(specifier, ExportSpecifier::Named(s) if s.is_type_only));
+                // export {} is kept if there weren't any specifiers to begin with
+                len == 0 || !n.specifiers.is_empty()
+            }
+            _ => true,
+        }
+    }
+}
+
+impl VisitMut for TypeImportElision {
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        let mut values = ValueUsage::default();
+        n.visit_with(&mut values);
+        let mut types = TypeUsage::default();
+        n.visit_with(&mut types);
+
+        n.body = n.body.take().into_iter().filter_map(|mut item| {
+            let keep = match &mut item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) => self.elide_import(decl, &values, &types),
+                ModuleItem::ModuleDecl(decl) => self.elide_export(decl),
+                ModuleItem::Stmt(_) => true,
+            };
+            keep.then_some(item)
+        }).collect();
+    }
+}
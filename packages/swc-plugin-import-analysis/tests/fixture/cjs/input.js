@@ -0,0 +1,14 @@
+const heredoc = require('heredoc');
+const path = require('path');
+
+if ('production' === 'development') {
+  require('./debug');
+} else {
+  require('./release');
+}
+
+const logger = true ? require('./logger') : require('./noop');
+
+require.async('./lazy', function(lazy) {
+  lazy(path, heredoc, logger);
+});
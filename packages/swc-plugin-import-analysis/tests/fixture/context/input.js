@@ -0,0 +1,6 @@
+const icons = require.context('./icons/', true, /\.svg$/i);
+const flat = require.context('./icons', false);
+const messages = require.context('./i18n', true, /^\.\/(en|zh)\//, 'lazy');
+const modules = require.context('./modules');
+const dir = './icons';
+const dynamic = require.context(dir, true, /\.svg$/);
@@ -0,0 +1,11 @@
+import React, { useState as useReactState } from 'react';
+import * as lodash from 'lodash';
+import './style.css';
+export * from './utils';
+export { default as Button, Input } from './components';
+
+export default function App() {
+  const [count] = useReactState(0);
+  import('./lazy').then(mod => console.log(mod));
+  return React.createElement('div', null, lodash.get(count));
+}
@@ -0,0 +1,6 @@
+const lazy = import.meta.glob('./data/*.json');
+const eager = import.meta.glob('./data/*.json', { eager: true });
+const quoted = import.meta.glob('./locales/*.json', { 'eager': true });
+const images = import.meta.glob('./images/*.png', { eager: true, as: 'url' });
+const texts = import.meta.glob('./locales/*', { eager: true, inline: 4096 });
+const titles = import.meta.glob('./locales/*.json', { inline: true, import: 'title' });
@@ -0,0 +1,19 @@
+import type { Foo } from './foo';
+import { Bar, type Baz } from './bar';
+import { Qux, Quux } from './qux';
+import Props, { Component } from './component';
+import * as types from './types';
+import { Plugin } from './plugin';
+import type Options from './options.d.ts';
+export type { Corge } from './corge';
+export { type Grault, garply } from './grault';
+
+class Thing extends Component<Props> implements Plugin {
+  bar: Bar = new Bar();
+  baz?: Baz;
+  qux: types.Qux[] = [];
+
+  render(options: Options): Quux {
+    return <Qux qux={this.qux} />;
+  }
+}
@@ -0,0 +1,26 @@
+use std::{fs::read_to_string, path::PathBuf};
+
+use swc_core::ecma::{
+    parser::{Syntax, TsConfig},
+    transforms::testing::Tester,
+};
+use swc_plugin_import_analysis::analyze_imports;
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fixture/**/input.*")]
+fn fixture(input: PathBuf) {
+    let output = input.parent().unwrap().join("output.json");
+    let syntax = match input.extension().and_then(|ext| ext.to_str()) {
+        Some("ts") | Some("tsx") => Syntax::Typescript(TsConfig { tsx: true, ..Default::default() }),
+        _ => Syntax::Es(Default::default()),
+    };
+    let src = read_to_string(&input).unwrap();
+
+    let result = Tester::run(|tester| {
+        let module = tester.with_parser("input.js", syntax, &src, |p| p.parse_module())?;
+        Ok(analyze_imports(&module))
+    });
+
+    let json = serde_json::to_string_pretty(&result).unwrap();
+    NormalizedOutput::from(json).compare_to_file(output).unwrap();
+}
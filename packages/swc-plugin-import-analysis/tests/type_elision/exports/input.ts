@@ -0,0 +1,8 @@
+import { Config } from './config';
+export type { Foo } from './foo';
+export { type Bar, baz } from './bar';
+export { type Qux } from './qux';
+export type * from './types';
+export * from './globals.d.ts';
+export * from './utils';
+export { Config };
@@ -0,0 +1,4 @@
+import { Config } from './config';
+export { baz } from './bar';
+export * from './utils';
+export { Config };
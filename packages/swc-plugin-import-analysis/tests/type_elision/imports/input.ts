@@ -0,0 +1,20 @@
+import type { Foo } from './foo';
+import { Bar, type Baz } from './bar';
+import Props, { Component } from './component';
+import * as types from './types';
+import { Plugin } from './plugin';
+import Options from './options.d.ts';
+import { Button } from './button';
+import { unused } from './unused';
+import './side-effect';
+
+export class Thing extends Component<Props> implements Plugin {
+  bar: Bar = new Bar();
+  baz?: Baz;
+  foo?: Foo;
+  qux: types.Qux[] = [];
+
+  render(options: Options) {
+    return <Button label={typeof options} />;
+  }
+}
@@ -0,0 +1,14 @@
+import { Bar } from './bar';
+import { Component } from './component';
+import { Button } from './button';
+import { unused } from './unused';
+import './side-effect';
+export class Thing extends Component<Props> implements Plugin {
+    bar: Bar = new Bar();
+    baz?: Baz;
+    foo?: Foo;
+    qux: types.Qux[] = [];
+    render(options: Options) {
+        return <Button label={typeof options}/>;
+    }
+}
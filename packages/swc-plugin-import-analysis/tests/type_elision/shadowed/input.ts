@@ -0,0 +1,12 @@
+import { Model, Schema, helper } from './model';
+
+function create(Model: string): Schema {
+  return { Model };
+}
+
+function format(value: Model) {
+  const helper = (x: unknown) => x;
+  return helper(value);
+}
+
+export { create, format };
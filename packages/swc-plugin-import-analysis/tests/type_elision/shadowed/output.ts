@@ -0,0 +1,11 @@
+import { helper } from './model';
+function create(Model: string): Schema {
+    return {
+        Model
+    };
+}
+function format(value: Model) {
+    const helper = (x: unknown)=>x;
+    return helper(value);
+}
+export { create, format };
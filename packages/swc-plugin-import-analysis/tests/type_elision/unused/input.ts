@@ -0,0 +1,8 @@
+import { register } from './register';
+import polyfill from './polyfill';
+import { type Options, setup } from './setup';
+import type { Config } from './config';
+
+export function init(options: Options, config?: Config) {
+  return options;
+}
@@ -0,0 +1,6 @@
+import { register } from './register';
+import polyfill from './polyfill';
+import { setup } from './setup';
+export function init(options: Options, config?: Config) {
+    return options;
+}
@@ -0,0 +1,27 @@
+use porter_swc_common::testing::FixtureOptions;
+use swc_core::{
+    common::{chain, Mark},
+    ecma::{
+        parser::{Syntax, TsConfig},
+        transforms::base::resolver,
+        visit::as_folder,
+    },
+};
+use swc_plugin_import_analysis::type_import_elision;
+
+porter_swc_common::fixture!(
+    fixture,
+    "tests/type_elision/**/input.ts",
+    FixtureOptions {
+        syntax: Syntax::Typescript(TsConfig { tsx: true, ..Default::default() }),
+        idempotent: true,
+    },
+    |_, _| {
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+        chain!(
+            resolver(unresolved_mark, top_level_mark, true),
+            as_folder(type_import_elision())
+        )
+    }
+);
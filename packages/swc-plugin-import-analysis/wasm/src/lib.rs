@@ -0,0 +1,18 @@
+use import_analysis::{import_analysis_transform, type_import_elision, Config};
+use porter_swc_common::config::plugin_config;
+use swc_core::{
+    ecma::{ast::Program, visit::VisitMutWith},
+    plugin::{plugin_transform, proxies::{PluginCommentsProxy, TransformPluginProgramMetadata}},
+};
+
+/// Leaves the program as is unless `elideTypeImports` is enabled, see
+/// [import_analysis_transform] for the reported result.
+#[plugin_transform]
+pub fn import_analysis_plugin(mut program: Program, metadata: TransformPluginProgramMetadata) -> Program {
+    let config: Config = plugin_config(&metadata, "swc-plugin-import-analysis");
+    if config.elide_type_imports {
+        program.visit_mut_with(&mut type_import_elision());
+    }
+    program.visit_mut_with(&mut import_analysis_transform(PluginCommentsProxy));
+    program
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::ast::{
+        ArrayLit, Bool, Expr, ExprOrSpread, Ident, KeyValueProp, Lit, Null, Number, ObjectLit, Prop,
+        PropName, PropOrSpread, Str, UnaryExpr, UnaryOp,
+    },
+};
+
+/// JSON value that keeps the order of the keys, which are printed in the same order as
+/// `JSON.parse()` would have them.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<JsonValue>),
+    Object(Vec<(String, JsonValue)>),
+}
+
+/// Invalid JSON with the location where it failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonError {
+    pub filename: String,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}:{}:{})", self.message, self.filename, self.line, self.column)
+    }
+}
+
+impl std::error::Error for JsonError {}
+
+/// Parses the JSON text, the errors are located by line and column.
+pub fn parse_json(source: &str, filename: &str) -> Result<JsonValue, JsonError> {
+    serde_json::from_str(source).map_err(|err| {
+        let location = format!(" at line {} column {}", err.line(), err.column());
+        let message = err.to_string();
+        JsonError {
+            filename: filename.to_string(),
+            line: err.line(),
+            column: err.column(),
+            message: message.strip_suffix(&location).unwrap_or(&message).to_string(),
+        }
+    })
+}
+
+impl JsonValue {
+    /// The value of the key if it is an object, duplicated keys are resolved when parsing.
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(entries) => entries.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    /// The equivalent literal expression.
+    pub fn to_expr(&self) -> Expr {
+        match self {
+            JsonValue::Null => Expr::Lit(Lit::Null(Null { span: DUMMY_SP })),
+            JsonValue::Bool(value) => Expr::Lit(Lit::Bool(Bool { span: DUMMY_SP, value: *value })),
+            JsonValue::Number(value) if value.is_sign_negative() && *value != 0.0 => Expr::Unary(UnaryExpr {
+                span: DUMMY_SP,
+                op: UnaryOp::Minus,
+                arg: Box::new(JsonValue::Number(-value).to_expr()),
+            }),
+            JsonValue::Number(value) => Expr::Lit(Lit::Num(Number { span: DUMMY_SP, value: *value, raw: None })),
+            JsonValue::String(value) => Expr::Lit(Lit::Str(Str::from(value.as_str()))),
+            JsonValue::Array(items) => Expr::Array(ArrayLit {
+                span: DUMMY_SP,
+                elems: items.iter().map(|item| Some(ExprOrSpread { spread: None, expr: Box::new(item.to_expr()) })).collect(),
+            }),
+            JsonValue::Object(entries) => Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: entries.iter().map(|(key, value)| key_value(key, value.to_expr())).collect(),
+            }),
+        }
+    }
+}
+
+/// `"key": value`, or `key: value` if the key is a valid identifier.
+pub(crate) fn key_value(key: &str, value: Expr) -> PropOrSpread {
+    let key = if Ident::verify_symbol(key).is_ok() {
+        PropName::Ident(Ident::new(key.into(), DUMMY_SP))
+    } else {
+        PropName::Str(Str::from(key))
+    };
+    PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp { key, value: Box::new(value) })))
+}
+
+impl<'de> Deserialize<'de> for JsonValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(JsonValueVisitor)
+    }
+}
+
+struct JsonValueVisitor;
+
+impl<'de> Visitor<'de> for JsonValueVisitor {
+    type Value = JsonValue;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("any valid JSON value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<JsonValue, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<JsonValue, E> {
+        Ok(JsonValue::Bool(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Number(value as f64))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Number(value as f64))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<JsonValue, E> {
+        Ok(JsonValue::Number(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<JsonValue, E> {
+        Ok(JsonValue::String(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: String) -> Result<JsonValue, E> {
+        Ok(JsonValue::String(value))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<JsonValue, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(JsonValue::Array(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonValue, A::Error> {
+        let mut entries: Vec<(String, JsonValue)> = Vec::new();
+        while let Some((key, value)) = map.next_entry::<String, JsonValue>()? {
+            // the last one wins, at the position of the first one, same as JSON.parse()
+            match entries.iter_mut().find(|(k, _)| *k == key) {
+                Some(entry) => entry.1 = value,
+                None => entries.push((key, value)),
+            }
+        }
+        Ok(JsonValue::Object(entries))
+    }
+}
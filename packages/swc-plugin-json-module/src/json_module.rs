@@ -0,0 +1,101 @@
+use serde::Deserialize;
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::ast::{
+        BindingIdent, Decl, ExportDecl, ExportDefaultExpr, Expr, Ident, Module, ModuleDecl, ModuleItem,
+        ObjectLit, Pat, Prop, PropOrSpread, VarDecl, VarDeclKind, VarDeclarator,
+    },
+};
+
+use crate::json::{key_value, JsonValue};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Config {
+    /// The exports being imported, e.g. `["default"]` or `["foo", "bar"]`, the rest of
+    /// the named exports are left out. All of them are exported if not specified.
+    pub exports: Option<Vec<String>>,
+}
+
+impl Config {
+    fn is_exported(&self, name: &str) -> bool {
+        match &self.exports {
+            Some(exports) => exports.iter().any(|export| export == name),
+            None => true,
+        }
+    }
+}
+
+/// The top level keys of the JSON object that can be named exports.
+pub fn named_exports(value: &JsonValue) -> Vec<&str> {
+    match value {
+        JsonValue::Object(entries) => entries
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .filter(|key| Ident::verify_symbol(key).is_ok())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Turns the JSON into an ES module, the top level keys that are valid identifiers are
+/// exported by name, which the default export shares:
+///
+/// ```js
+/// export const foo = { bar: 1 };
+/// export default { foo, "foo-bar": 2 };
+/// ```
+pub fn json_module(value: &JsonValue, config: &Config) -> Module {
+    let named = named_exports(value);
+    let mut body = Vec::new();
+    if let JsonValue::Object(entries) = value {
+        for (key, value) in entries {
+            if named.contains(&key.as_str()) && config.is_exported(key) {
+                body.push(export_const(key, value.to_expr()));
+            }
+        }
+    }
+
+    if config.is_exported("default") {
+        let expr = match value {
+            JsonValue::Object(entries) => Expr::Object(ObjectLit {
+                span: DUMMY_SP,
+                props: entries
+                    .iter()
+                    .map(|(key, value)| {
+                        if named.contains(&key.as_str()) && config.is_exported(key) {
+                            let id = Ident::new(key.as_str().into(), DUMMY_SP);
+                            PropOrSpread::Prop(Box::new(Prop::Shorthand(id)))
+                        } else {
+                            key_value(key, value.to_expr())
+                        }
+                    })
+                    .collect(),
+            }),
+            value => value.to_expr(),
+        };
+        body.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+            span: DUMMY_SP,
+            expr: Box::new(expr),
+        })));
+    }
+
+    Module { span: DUMMY_SP, body, shebang: None }
+}
+
+fn export_const(name: &str, init: Expr) -> ModuleItem {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+        span: DUMMY_SP,
+        decl: Decl::Var(Box::new(VarDecl {
+            span: DUMMY_SP,
+            kind: VarDeclKind::Const,
+            declare: false,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: Pat::Ident(BindingIdent::from(Ident::new(name.into(), DUMMY_SP))),
+                init: Some(Box::new(init)),
+                definite: false,
+            }],
+        })),
+    }))
+}
@@ -0,0 +1,4 @@
+pub use self::json::*;
+pub use self::json_module::*;
+pub mod json;
+pub mod json_module;
@@ -0,0 +1,8 @@
+export default [
+    {
+        id: 1
+    },
+    {
+        id: 2
+    }
+];
@@ -0,0 +1,6 @@
+export const a = 3;
+export const b = 2;
+export default {
+    a,
+    b
+};
@@ -0,0 +1,2 @@
+export const name = "porter";
+export const $scope = "ident";
@@ -0,0 +1,24 @@
+export const name = "porter";
+export const version = 4;
+export const offset = -1.5;
+export const license = null;
+export const $scope = "ident";
+export const emoji = '😀 "quoted" \n';
+export default {
+    name,
+    version,
+    offset,
+    "private": true,
+    license,
+    "foo-bar": {
+        "nested-key": [
+            1,
+            "two",
+            false
+        ]
+    },
+    "default": "reserved",
+    "class": "reserved",
+    $scope,
+    emoji
+};
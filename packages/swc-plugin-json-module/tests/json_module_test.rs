@@ -0,0 +1,39 @@
+use std::{fs, path::PathBuf};
+
+use swc_core::{
+    common::{sync::Lrc, SourceMap},
+    ecma::codegen::{text_writer::JsWriter, Emitter},
+};
+use swc_plugin_json_module::{json_module, parse_json, Config};
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fixture/**/input.json")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let source = fs::read_to_string(&input).unwrap();
+    let config: Config = match fs::read_to_string(dir.join("options.json")) {
+        Ok(json) => serde_json::from_str(&json).unwrap(),
+        Err(_) => Default::default(),
+    };
+
+    let value = match parse_json(&source, "input.json") {
+        Ok(value) => value,
+        Err(err) => {
+            NormalizedOutput::from(err.to_string()).compare_to_file(dir.join("output.stderr")).unwrap();
+            return;
+        }
+    };
+    let module = json_module(&value, &config);
+    let cm: Lrc<SourceMap> = Default::default();
+    let mut buf = Vec::new();
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: JsWriter::new(cm, "\n", &mut buf, None),
+        };
+        emitter.emit_module(&module).unwrap();
+    }
+    NormalizedOutput::from(String::from_utf8(buf).unwrap()).compare_to_file(dir.join("output.js")).unwrap();
+}
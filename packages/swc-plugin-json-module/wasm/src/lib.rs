@@ -0,0 +1,19 @@
+use json::{json_module, parse_json, Config};
+use porter_swc_common::config::{plugin_config, plugin_filepath};
+use swc_core::{
+    ecma::ast::Program,
+    plugin::{metadata::TransformPluginMetadataContextKind, plugin_transform, proxies::TransformPluginProgramMetadata},
+};
+
+/// Reads the JSON file through the `/cwd` mount and replaces the program with the module,
+/// hence the program passed in can be anything, e.g. an empty one. The config is
+/// documented in [Config].
+#[plugin_transform]
+pub fn json_module_plugin(_program: Program, metadata: TransformPluginProgramMetadata) -> Program {
+    let filename = metadata.get_context(&TransformPluginMetadataContextKind::Filename).expect("filename required");
+    let filepath = plugin_filepath(&metadata);
+    let config: Config = plugin_config(&metadata, "swc-plugin-json-module");
+    let source = std::fs::read_to_string(&filepath).expect("failed to read json");
+    let value = parse_json(&source, &filename).unwrap_or_else(|err| panic!("{err}"));
+    Program::Module(json_module(&value, &config))
+}
@@ -0,0 +1,2 @@
+pub use self::named_import::*;
+pub mod named_import;
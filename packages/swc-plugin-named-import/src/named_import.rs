@@ -0,0 +1,319 @@
+use porter_swc_common::ast::{call, default_specifier, expr_stmt, ident_expr, import_decl, member, str_lit, var_decl};
+use serde::Deserialize;
+use swc_core::{
+    common::util::take::Take,
+    ecma::{
+        ast::{
+            CallExpr, Callee, Decl, Expr, Ident, ImportDecl, ImportSpecifier, Lit, ModuleDecl, ModuleExportName,
+            ModuleItem, ObjectPatProp, Pat, PropName, Stmt, Str, VarDecl, VarDeclKind, VarDeclarator,
+        },
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ComponentCase {
+    Camel,
+    Kebab,
+    Snake,
+}
+
+/// `style: true` imports `lib/button/style`, `style: "css"` imports `lib/button/style/css`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum StyleOption {
+    Bool(bool),
+    File(String),
+    Resolver(StyleResolver),
+}
+
+/// Resolves style files that are not in the component directory, e.g.
+/// `{ "path": "element-ui/lib/theme-chalk/[name].css" }`, where `[name]` is the
+/// component name in `componentCase`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct StyleResolver {
+    pub path: String,
+}
+
+impl Default for StyleOption {
+    fn default() -> Self {
+        StyleOption::Bool(true)
+    }
+}
+
+/// Same as the ImportOption in porter/src/named_import.ts, which resembles the options
+/// of babel-plugin-import.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ImportOption {
+    pub library_name: String,
+    pub library_directory: String,
+    #[serde(rename = "camel2DashComponentName")]
+    pub camel2_dash_component_name: Option<bool>,
+    pub component_case: Option<ComponentCase>,
+    pub style: StyleOption,
+    pub cjs: bool,
+}
+
+impl Default for ImportOption {
+    fn default() -> Self {
+        ImportOption {
+            library_name: "antd".to_string(),
+            library_directory: "lib".to_string(),
+            camel2_dash_component_name: None,
+            component_case: None,
+            style: Default::default(),
+            cjs: false,
+        }
+    }
+}
+
+impl ImportOption {
+    /// `componentCase` has priority over `camel2DashComponentName`
+    fn component_case(&self) -> ComponentCase {
+        match (self.component_case, self.camel2_dash_component_name) {
+            (Some(case), _) => case,
+            (None, Some(false)) => ComponentCase::Camel,
+            _ => ComponentCase::Kebab,
+        }
+    }
+
+    fn component_specifier(&self, name: &str) -> String {
+        let ImportOption { library_name, library_directory, .. } = self;
+        let chunk = decamelize(name, self.component_case());
+        if library_directory.is_empty() {
+            format!("{library_name}/{chunk}")
+        } else {
+            format!("{library_name}/{library_directory}/{chunk}")
+        }
+    }
+
+    fn style_specifier(&self, name: &str) -> Option<String> {
+        let specifier = self.component_specifier(name);
+        match &self.style {
+            StyleOption::Bool(false) => None,
+            StyleOption::Bool(true) => Some(format!("{specifier}/style")),
+            StyleOption::File(file) => Some(format!("{specifier}/style/{file}")),
+            StyleOption::Resolver(StyleResolver { path }) => {
+                Some(path.replace("[name]", &decamelize(name, self.component_case())))
+            }
+        }
+    }
+}
+
+/// Either one library or several libraries to be transformed in the same pass.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Config {
+    Libraries(Vec<ImportOption>),
+    Library(ImportOption),
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::Library(Default::default())
+    }
+}
+
+impl From<Config> for Vec<ImportOption> {
+    fn from(config: Config) -> Self {
+        match config {
+            Config::Library(option) => vec![option],
+            Config::Libraries(options) => options,
+        }
+    }
+}
+
+struct Component {
+    name: String,
+    local: Ident,
+}
+
+pub fn named_import_transform(options: Vec<ImportOption>) -> impl VisitMut {
+    NamedImport { options }
+}
+
+struct NamedImport {
+    options: Vec<ImportOption>,
+}
+
+fn decamelize(name: &str, case: ComponentCase) -> String {
+    let separator = match case {
+        ComponentCase::Camel => None,
+        ComponentCase::Kebab => Some('-'),
+        ComponentCase::Snake => Some('_'),
+    };
+    let mut result = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if i == 0 {
+            result.extend(c.to_lowercase());
+        } else if let (true, Some(separator)) = (c.is_uppercase(), separator) {
+            result.push(separator);
+            result.extend(c.to_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+impl NamedImport {
+    fn find_option(&self, src: &Str) -> Option<&ImportOption> {
+        self.options.iter().find(|option| *src.value == *option.library_name)
+    }
+
+    fn require_call(&self, specifier: &str) -> Expr {
+        call(ident_expr("require"), vec![str_lit(specifier)])
+    }
+
+    /// `import Button from "antd/lib/button"` or `const Button = require("antd/lib/button").default`
+    fn import_component(&self, option: &ImportOption, component: &Component, cjs: bool) -> ModuleItem {
+        let specifier = option.component_specifier(&component.name);
+        if !cjs {
+            return import_decl(&specifier, vec![default_specifier(component.local.clone())]);
+        }
+        let init = member(self.require_call(&specifier), "default");
+        ModuleItem::Stmt(var_decl(VarDeclKind::Const, vec![(component.local.clone(), Some(init))]))
+    }
+
+    /// `import "antd/lib/button/style"` or `require("antd/lib/button/style")`
+    fn import_style(&self, specifier: String, cjs: bool) -> ModuleItem {
+        if !cjs {
+            return import_decl(&specifier, Vec::new());
+        }
+        ModuleItem::Stmt(expr_stmt(self.require_call(&specifier)))
+    }
+
+    fn import_components(&self, option: &ImportOption, components: &[Component], cjs: bool) -> Vec<ModuleItem> {
+        let scripts = components.iter().map(|component| self.import_component(option, component, cjs));
+        let styles = components
+            .iter()
+            .filter_map(|component| option.style_specifier(&component.name))
+            .map(|specifier| self.import_style(specifier, cjs));
+        scripts.chain(styles).collect()
+    }
+
+    /// `import { Button, DatePicker as Picker } from 'antd'`, the specifiers that can not
+    /// be transformed such as `import antd from 'antd'` are left in the declaration.
+    fn transform_import(&self, decl: &mut ImportDecl) -> Option<(&ImportOption, Vec<Component>)> {
+        if decl.type_only { return None; }
+        let option = self.find_option(&decl.src)?;
+        let mut components = Vec::new();
+        decl.specifiers.retain(|specifier| {
+            let ImportSpecifier::Named(named) = specifier else { return true };
+            if named.is_type_only { return true; }
+            let name = match &named.imported {
+                Some(ModuleExportName::Ident(id)) => id.sym.to_string(),
+                Some(ModuleExportName::Str(_)) => return true,
+                None => named.local.sym.to_string(),
+            };
+            components.push(Component { name, local: named.local.clone() });
+            false
+        });
+        Some((option, components))
+    }
+
+    /// `const { Button, DatePicker: Picker } = require('antd')`, which is left as is if
+    /// there were rest elements or default values.
+    fn transform_require(&self, declarator: &VarDeclarator) -> Option<(&ImportOption, Vec<Component>)> {
+        let Expr::Call(CallExpr { callee: Callee::Expr(callee), args, .. }) = &**declarator.init.as_ref()? else { return None };
+        if !matches!(&**callee, Expr::Ident(id) if &*id.sym == "require") || args.len() != 1 { return None; }
+        let Expr::Lit(Lit::Str(src)) = &*args[0].expr else { return None };
+        let option = self.find_option(src)?;
+        let Pat::Object(pat) = &declarator.name else { return None };
+
+        let mut components = Vec::new();
+        for prop in pat.props.iter() {
+            let (name, local) = match prop {
+                ObjectPatProp::Assign(prop) if prop.value.is_none() => (prop.key.sym.to_string(), prop.key.clone()),
+                ObjectPatProp::KeyValue(prop) => {
+                    let name = match &prop.key {
+                        PropName::Ident(id) => id.sym.to_string(),
+                        PropName::Str(s) => s.value.to_string(),
+                        _ => return None,
+                    };
+                    let Pat::Ident(local) = &*prop.value else { return None };
+                    (name, local.id.clone())
+                }
+                _ => return None,
+            };
+            components.push(Component { name, local });
+        }
+        Some((option, components))
+    }
+
+    /// Splits the var declaration if `const { Button } = require('antd')` were found.
+    fn transform_var_decl(&self, var: &mut VarDecl) -> Option<Vec<ModuleItem>> {
+        let index = var.decls.iter().position(|decl| self.transform_require(decl).is_some())?;
+        let (option, components) = self.transform_require(&var.decls[index]).unwrap();
+        let rest = var.decls.split_off(index + 1);
+        var.decls.pop();
+
+        let mut items = Vec::new();
+        if !var.decls.is_empty() {
+            items.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(var.take())))));
+        }
+        items.extend(self.import_components(option, &components, true));
+        if !rest.is_empty() {
+            let mut var = VarDecl { decls: rest, ..var.clone() };
+            match self.transform_var_decl(&mut var) {
+                Some(result) => items.extend(result),
+                None => items.push(ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(var))))),
+            }
+        }
+        Some(items)
+    }
+}
+
+impl VisitMut for NamedImport {
+    // Implement necessary visit_mut_* methods for actual custom transform.
+    // A comprehensive list of possible visitor methods can be found here:
+    // https://rustdoc.swc.rs/swc_ecma_visit/trait.VisitMut.html
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+
+        let mut result = Vec::with_capacity(items.len());
+        for mut item in items.take() {
+            match &mut item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) => {
+                    let Some((option, components)) = self.transform_import(decl) else {
+                        result.push(item);
+                        continue;
+                    };
+                    let imports = self.import_components(option, &components, option.cjs);
+                    // import antd, { Button } from 'antd';
+                    if !decl.specifiers.is_empty() || components.is_empty() {
+                        result.push(item);
+                    }
+                    result.extend(imports);
+                }
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(var))) => {
+                    match self.transform_var_decl(var) {
+                        Some(items) => result.extend(items),
+                        None => result.push(item),
+                    }
+                }
+                _ => result.push(item),
+            }
+        }
+        *items = result;
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        stmts.visit_mut_children_with(self);
+
+        let mut result = Vec::with_capacity(stmts.len());
+        for mut stmt in stmts.take() {
+            let Stmt::Decl(Decl::Var(var)) = &mut stmt else {
+                result.push(stmt);
+                continue;
+            };
+            match self.transform_var_decl(var) {
+                Some(items) => result.extend(items.into_iter().filter_map(|item| item.stmt())),
+                None => result.push(stmt),
+            }
+        }
+        *stmts = result;
+    }
+}
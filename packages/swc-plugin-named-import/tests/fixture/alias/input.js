@@ -0,0 +1,8 @@
+// comments with import { Fake } from "antd"; should be left alone
+import antd, { Button as AntButton, /* Card, */ DatePicker } from "antd";
+const { Select: AntSelect, Input } = require("antd"), moment = require("moment");
+
+function render() {
+  const { Modal } = require("antd");
+  return [antd, AntButton, DatePicker, AntSelect, Input, moment, Modal];
+}
@@ -0,0 +1,24 @@
+// comments with import { Fake } from "antd"; should be left alone
+import antd from "antd";
+import AntButton from "antd/lib/button";
+import /* Card, */ DatePicker from "antd/lib/date-picker";
+import "antd/lib/button/style";
+import "antd/lib/date-picker/style";
+const AntSelect = require("antd/lib/select").default;
+const Input = require("antd/lib/input").default;
+require("antd/lib/select/style");
+require("antd/lib/input/style");
+const moment = require("moment");
+function render() {
+    const Modal = require("antd/lib/modal").default;
+    require("antd/lib/modal/style");
+    return [
+        antd,
+        AntButton,
+        DatePicker,
+        AntSelect,
+        Input,
+        moment,
+        Modal
+    ];
+}
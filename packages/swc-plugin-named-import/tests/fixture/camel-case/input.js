@@ -0,0 +1 @@
+import { differenceBy } from "lodash"
@@ -0,0 +1 @@
+import { DatePicker } from "antd";
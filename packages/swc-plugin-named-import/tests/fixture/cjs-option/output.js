@@ -0,0 +1,2 @@
+const DatePicker = require("antd/lib/date-picker").default;
+require("antd/lib/date-picker/style");
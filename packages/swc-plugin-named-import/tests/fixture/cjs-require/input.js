@@ -0,0 +1,2 @@
+const React = require("react");
+const { Select, Button, Toggle } = require("antd");
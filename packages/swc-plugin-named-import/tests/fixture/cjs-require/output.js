@@ -0,0 +1,7 @@
+const React = require("react");
+const Select = require("antd/lib/select").default;
+const Button = require("antd/lib/button").default;
+const Toggle = require("antd/lib/toggle").default;
+require("antd/lib/select/style/css");
+require("antd/lib/button/style/css");
+require("antd/lib/toggle/style/css");
@@ -0,0 +1 @@
+import { DifferenceBy } from "lodash"
@@ -0,0 +1 @@
+import{Card,Checkbox}from"antd";
@@ -0,0 +1,4 @@
+import Card from "antd/lib/card";
+import Checkbox from "antd/lib/checkbox";
+import "antd/lib/card/style/css";
+import "antd/lib/checkbox/style/css";
@@ -0,0 +1,2 @@
+import React, { useCallback, useRef } from "react";
+import { Select, Button, Toggle } from "antd";
@@ -0,0 +1,7 @@
+import React, { useCallback, useRef } from "react";
+import Select from "antd/lib/select";
+import Button from "antd/lib/button";
+import Toggle from "antd/lib/toggle";
+import "antd/lib/select/style/css";
+import "antd/lib/button/style/css";
+import "antd/lib/toggle/style/css";
@@ -0,0 +1,10 @@
+import React from "react";
+import {
+  Upload,
+  Button,
+  Tab,
+  Toggle,
+  InplaceEditor,
+  Loading,
+  Tag,
+} from "antd";
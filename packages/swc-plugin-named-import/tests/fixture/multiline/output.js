@@ -0,0 +1,15 @@
+import React from "react";
+import Upload from "antd/lib/upload";
+import Button from "antd/lib/button";
+import Tab from "antd/lib/tab";
+import Toggle from "antd/lib/toggle";
+import InplaceEditor from "antd/lib/inplace-editor";
+import Loading from "antd/lib/loading";
+import Tag from "antd/lib/tag";
+import "antd/lib/upload/style/css";
+import "antd/lib/button/style/css";
+import "antd/lib/tab/style/css";
+import "antd/lib/toggle/style/css";
+import "antd/lib/inplace-editor/style/css";
+import "antd/lib/loading/style/css";
+import "antd/lib/tag/style/css";
@@ -0,0 +1,4 @@
+import { Button, DatePicker } from "antd";
+import { Button as ElButton, TimePicker } from "element-ui";
+import { debounce, cloneDeep } from "lodash";
+const { Toast } = require("antd-mobile");
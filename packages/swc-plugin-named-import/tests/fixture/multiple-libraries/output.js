@@ -0,0 +1,12 @@
+import Button from "antd/es/button";
+import DatePicker from "antd/es/date-picker";
+import "antd/es/button/style/css";
+import "antd/es/date-picker/style/css";
+import ElButton from "element-ui/lib/button";
+import TimePicker from "element-ui/lib/time-picker";
+import "element-ui/lib/theme-chalk/button.css";
+import "element-ui/lib/theme-chalk/time-picker.css";
+import debounce from "lodash/debounce";
+import cloneDeep from "lodash/cloneDeep";
+const Toast = require("antd-mobile/lib/toast").default;
+require("antd-mobile/lib/toast/style");
@@ -0,0 +1,2 @@
+import React from "react";
+import { Loading, Card, Tab } from "antd";
@@ -0,0 +1,7 @@
+import React from "react";
+import Loading from "antd/lib/loading";
+import Card from "antd/lib/card";
+import Tab from "antd/lib/tab";
+import "antd/lib/loading/style/css";
+import "antd/lib/card/style/css";
+import "antd/lib/tab/style/css";
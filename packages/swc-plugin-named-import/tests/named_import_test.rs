@@ -0,0 +1,13 @@
+use porter_swc_common::testing::FixtureOptions;
+use swc_core::ecma::{transforms::testing::parse_options, visit::as_folder};
+use swc_plugin_named_import::{named_import_transform, Config};
+
+porter_swc_common::fixture!(
+    fixture,
+    "tests/fixture/**/input.js",
+    FixtureOptions { idempotent: true, ..Default::default() },
+    |input, _| {
+        let config: Config = parse_options(input.parent().unwrap());
+        as_folder(named_import_transform(config.into()))
+    }
+);
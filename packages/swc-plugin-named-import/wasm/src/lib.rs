@@ -0,0 +1,17 @@
+use named_import::{named_import_transform, Config};
+use porter_swc_common::config::plugin_config;
+use swc_core::{
+    ecma::{ast::Program, visit::VisitMutWith},
+    plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
+};
+
+/// Rewrites `import { Button } from 'antd'` into `import Button from 'antd/lib/button'`
+/// and `import 'antd/lib/button/style'`, the config is either an [named_import::ImportOption]
+/// or an array of them.
+#[plugin_transform]
+pub fn named_import_plugin(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
+    let config: Config = plugin_config(&metadata, "swc-plugin-named-import");
+    let mut program = program;
+    program.visit_mut_with(&mut named_import_transform(config.into()));
+    program
+}
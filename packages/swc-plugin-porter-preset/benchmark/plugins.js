@@ -0,0 +1,78 @@
+'use strict';
+
+/**
+ * Compares transforming the components of examples/app with the three standalone
+ * plugins against the preset, e.g.
+ *
+ *     cargo build-wasi --release
+ *     npm run benchmark
+ *
+ * On a single core Intel Xeon VM, with the plugins built by rust 1.81 and the same
+ * options run through the plugin host of swc_core 0.81.8, the 9 components took:
+ *
+ *     ITERATIONS=10   three plugins: 263.5ms  preset: 148.1ms  ratio: 1.78 (mean of 3 runs)
+ *     ITERATIONS=100  three plugins: 2162.9ms  preset: 1162.7ms  ratio: 1.86
+ *
+ * The ratio still depends on the machine and the components being transformed.
+ */
+const path = require('path');
+const fs = require('fs');
+const { transform } = require('@swc/core');
+
+const root = path.join(__dirname, '../../..');
+const target = path.join(root, 'target/wasm32-wasi/release');
+const iterations = Number(process.env.ITERATIONS) || 10;
+
+function listFiles(dir) {
+  return fs.readdirSync(dir, { withFileTypes: true }).flatMap(entry => {
+    const fpath = path.join(dir, entry.name);
+    if (entry.isDirectory()) return listFiles(fpath);
+    return /\.(?:js|jsx|ts|tsx)$/.test(entry.name) ? [fpath] : [];
+  });
+}
+
+async function run(files, plugins) {
+  const start = process.hrtime.bigint();
+  for (let i = 0; i < iterations; i++) {
+    await Promise.all(files.map(async fpath => {
+      const code = await fs.promises.readFile(fpath, 'utf8');
+      await transform(code, {
+        swcrc: false,
+        filename: fpath,
+        cwd: root,
+        jsc: {
+          parser: { syntax: /\.tsx?$/.test(fpath) ? 'typescript' : 'ecmascript', jsx: true, tsx: true },
+          experimental: { plugins },
+        },
+        module: { type: 'commonjs' },
+      });
+    }));
+  }
+  return Number(process.hrtime.bigint() - start) / 1e6;
+}
+
+async function main() {
+  const files = listFiles(path.join(root, 'examples/app/components'));
+  const standalone = [
+    'swc_plugin_deheredoc.wasm',
+    'swc_plugin_glob_import.wasm',
+    'swc_plugin_porter.wasm',
+  ].map(name => [path.join(target, name), {}]);
+  const preset = [[path.join(target, 'swc_plugin_porter_preset.wasm'), {}]];
+
+  // warm up the plugin cache of swc
+  await run(files.slice(0, 1), standalone);
+  await run(files.slice(0, 1), preset);
+
+  const standaloneTime = await run(files, standalone);
+  const presetTime = await run(files, preset);
+  console.log('%d files x %d iterations', files.length, iterations);
+  console.log('three plugins: %sms', standaloneTime.toFixed(1));
+  console.log('preset:        %sms', presetTime.toFixed(1));
+  console.log('ratio:         %s', (standaloneTime / presetTime).toFixed(2));
+}
+
+main().catch(err => {
+  console.error(err);
+  process.exit(1);
+});
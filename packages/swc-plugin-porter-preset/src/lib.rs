@@ -0,0 +1,108 @@
+use serde::Deserialize;
+use swc_core::{ecma::{
+    ast::{Module, Script},
+    visit::{VisitMut, VisitMutWith},
+}, common::comments::Comments};
+use swc_plugin_deheredoc::deheredoc_transform;
+use swc_plugin_glob_import::glob_import_transform;
+use swc_plugin_porter::{porter_transform, Metadata};
+
+/// Options shared by the transforms in the preset, each of which can be turned off, e.g.
+///
+/// ```json
+/// { "deheredoc": false, "porter": { "resolve": { "react": "react/18.2.0/index.js" } } }
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Config {
+    /// `heredoc(function() {/* ... */})` into string literals, see swc-plugin-deheredoc.
+    pub deheredoc: bool,
+
+    /// `import.meta.glob()` into static or dynamic imports, see swc-plugin-glob-import.
+    pub glob_import: bool,
+
+    /// `true` or `false` to turn swc-plugin-porter on or off, or the options of it.
+    pub porter: PorterOption,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { deheredoc: true, glob_import: true, porter: PorterOption::Enabled(true) }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum PorterOption {
+    Enabled(bool),
+    Config(swc_plugin_porter::Config),
+}
+
+impl PorterOption {
+    fn config(&self) -> Option<swc_plugin_porter::Config> {
+        match self {
+            PorterOption::Enabled(true) => Some(Default::default()),
+            PorterOption::Enabled(false) => None,
+            PorterOption::Config(config) => Some(config.clone()),
+        }
+    }
+}
+
+/// Runs deheredoc, glob import and porter in the same order as they used to be listed
+/// in `jsc.experimental.plugins`, with one plugin instead of three.
+pub fn porter_preset<C>(filepath: String, config: Config, comments: C) -> PorterPreset<C>
+where
+    C: Comments + Clone,
+{
+    PorterPreset { filepath, config, comments, metadata: Default::default() }
+}
+
+pub struct PorterPreset<C>
+where
+    C: Comments + Clone,
+{
+    filepath: String,
+    config: Config,
+    comments: C,
+    metadata: Metadata,
+}
+
+impl<C> PorterPreset<C>
+where
+    C: Comments + Clone,
+{
+    /// Collected by swc-plugin-porter, which is also emitted in the trailing comment.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    fn visit_mut_passes<N>(&mut self, n: &mut N)
+    where
+        N: for<'a> VisitMutWith<dyn VisitMut + 'a>,
+    {
+        if self.config.deheredoc {
+            n.visit_mut_with(&mut deheredoc_transform(self.comments.clone()) as &mut dyn VisitMut);
+        }
+        if self.config.glob_import {
+            n.visit_mut_with(&mut glob_import_transform(self.filepath.clone()) as &mut dyn VisitMut);
+        }
+        if let Some(config) = self.config.porter.config() {
+            let mut porter = porter_transform(self.filepath.clone(), config, self.comments.clone());
+            n.visit_mut_with(&mut porter as &mut dyn VisitMut);
+            self.metadata = porter.metadata().clone();
+        }
+    }
+}
+
+impl<C> VisitMut for PorterPreset<C>
+where
+    C: Comments + Clone,
+{
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        self.visit_mut_passes(n);
+    }
+
+    fn visit_mut_script(&mut self, n: &mut Script) {
+        self.visit_mut_passes(n);
+    }
+}
@@ -0,0 +1,2 @@
+const emoji = import.meta.glob('../../data/emoji/*.svg', { eager: true, as: 'url' });
+console.log(emoji);
@@ -0,0 +1,5 @@
+const emoji = {
+    "../../data/emoji/grinning.svg": new URL("/grinning.5d41402a.svg", require.meta.url).href,
+    "../../data/emoji/winking.svg": new URL("/winking.7d793037.svg", require.meta.url).href
+};
+console.log(emoji); //# porterMetadata={"assets":[{"specifier":"../../data/emoji/grinning.svg","kind":"url"},{"specifier":"../../data/emoji/winking.svg","kind":"url"}]}
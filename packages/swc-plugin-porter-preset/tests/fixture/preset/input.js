@@ -0,0 +1,9 @@
+import heredoc from 'heredoc';
+import React from 'react';
+
+const template = heredoc(function() {/*
+  <div class="greeting"></div>
+*/});
+const locales = import.meta.glob('../../data/locales/*.json', { eager: true });
+const worker = new Worker(new URL('./worker.js', import.meta.url));
+console.log(React, template, locales, worker, import.meta.url);
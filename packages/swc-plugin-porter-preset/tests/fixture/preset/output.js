@@ -0,0 +1,10 @@
+import * as __glob_0_0 from "../../data/locales/en.json.js";
+import * as __glob_0_1 from "../../data/locales/zh.json.js";
+import React from "react/18.2.0/index.js";
+const template = '<div class="greeting"></div>';
+const locales = {
+    "../../data/locales/en.json": __glob_0_0,
+    "../../data/locales/zh.json": __glob_0_1
+};
+const worker = new Worker(new URL("/worker.8a3f12.js", require.meta.url));
+console.log(React, template, locales, worker, require.meta.url); //# porterMetadata={"assets":[{"specifier":"./worker.js","kind":"worker"}]}
@@ -0,0 +1,11 @@
+import * as __glob_0_0 from "../../data/locales/en.json";
+import * as __glob_0_1 from "../../data/locales/zh.json";
+import heredoc from 'heredoc';
+import React from 'react';
+const template = heredoc(function() {});
+const locales = {
+    "../../data/locales/en.json": __glob_0_0,
+    "../../data/locales/zh.json": __glob_0_1
+};
+const worker = new Worker(new URL('./worker.js', import.meta.url));
+console.log(React, template, locales, worker, import.meta.url);
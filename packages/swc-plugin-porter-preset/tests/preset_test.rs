@@ -0,0 +1,8 @@
+use swc_core::ecma::{transforms::testing::parse_options, visit::as_folder};
+use swc_plugin_porter_preset::{porter_preset, Config};
+
+porter_swc_common::fixture!(fixture, "tests/fixture/**/input.js", |input, t| {
+    // options.json in fixture directory is passed as plugin config
+    let config: Config = parse_options(input.parent().unwrap());
+    as_folder(porter_preset(input.to_str().unwrap().to_string(), config, t.comments.clone()))
+});
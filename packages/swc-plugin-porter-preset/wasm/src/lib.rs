@@ -0,0 +1,15 @@
+use porter_swc_common::config::{plugin_config, plugin_filepath};
+use preset::porter_preset;
+use swc_core::{
+    ecma::{ast::Program, visit::{as_folder, FoldWith}},
+    plugin::{plugin_transform, proxies::{PluginCommentsProxy, TransformPluginProgramMetadata}},
+};
+
+/// Replaces swc_plugin_deheredoc.wasm, swc_plugin_glob_import.wasm and swc_plugin_porter.wasm
+/// with one plugin, the config is documented in [preset::Config].
+#[plugin_transform]
+pub fn preset_plugin(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
+    let filepath = plugin_filepath(&metadata);
+    let config = plugin_config(&metadata, "swc-plugin-porter-preset");
+    program.fold_with(&mut as_folder(porter_preset(filepath, config, PluginCommentsProxy)))
+}
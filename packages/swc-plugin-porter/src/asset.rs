@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+
+use porter_swc_common::ast::{binding, ident, ident_expr, new_expr, new_url};
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::{
+        ast::{
+            BlockStmt, Decl, Expr, ExprOrSpread, FnDecl, Function, ImportSpecifier, Lit, MemberExpr,
+            MemberProp, MetaPropExpr, MetaPropKind, ModuleDecl, ModuleItem, NewExpr, Param, ReturnStmt, Stmt, Str,
+        },
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+use crate::metadata::{AssetKind, AssetReference};
+
+/// Reports assets referenced with `new URL('./foo.wasm', import.meta.url)` or
+/// `new Worker(new URL('./worker.js', import.meta.url))`, and rewrites the specifiers
+/// to the hashed output urls in porter's manifest. Workers imported with `?worker` or
+/// `?sharedworker` are turned into constructors of the same kind:
+///
+/// ```js
+/// import MyWorker from './worker.js?worker';
+/// // =>
+/// function MyWorker(options) {
+///     return new Worker(new URL('./worker.9e107d9d.js', import.meta.url), options);
+/// }
+/// ```
+pub struct AssetVisitor<'a> {
+    manifest: &'a HashMap<String, String>,
+    assets: &'a mut Vec<AssetReference>,
+}
+
+impl<'a> AssetVisitor<'a> {
+    pub fn new(manifest: &'a HashMap<String, String>, assets: &'a mut Vec<AssetReference>) -> Self {
+        AssetVisitor { manifest, assets }
+    }
+
+    /// `Worker` or `SharedWorker`, the kind of the worker being constructed.
+    fn worker_kind(&self, n: &NewExpr) -> Option<AssetKind> {
+        match &*n.callee {
+            Expr::Ident(id) if &*id.sym == "Worker" => Some(AssetKind::Worker),
+            Expr::Ident(id) if &*id.sym == "SharedWorker" => Some(AssetKind::SharedWorker),
+            _ => None,
+        }
+    }
+
+    fn is_callee(&self, n: &NewExpr, name: &str) -> bool {
+        matches!(&*n.callee, Expr::Ident(id) if &*id.sym == name)
+    }
+
+    /// `import MyWorker from './worker.js?worker'` into the function that constructs the
+    /// worker with `new URL()`, which is reported and rewritten as the other workers.
+    fn worker_import(&self, item: &ModuleItem) -> Option<ModuleItem> {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) = item else { return None };
+        let [ImportSpecifier::Default(specifier)] = decl.specifiers.as_slice() else { return None };
+        if decl.type_only {
+            return None;
+        }
+        let (path, query) = decl.src.value.split_once('?')?;
+        let callee = match query {
+            "worker" => "Worker",
+            "sharedworker" => "SharedWorker",
+            _ => return None,
+        };
+
+        let worker = new_expr(ident_expr(callee), vec![new_url(path), ident_expr("options")]);
+        let body = BlockStmt {
+            span: DUMMY_SP,
+            stmts: vec![Stmt::Return(ReturnStmt { span: DUMMY_SP, arg: Some(Box::new(worker)) })],
+        };
+        let function = Function {
+            params: vec![Param { span: DUMMY_SP, decorators: vec![], pat: binding(ident("options")) }],
+            decorators: vec![],
+            span: decl.span,
+            body: Some(body),
+            is_generator: false,
+            is_async: false,
+            type_params: None,
+            return_type: None,
+        };
+        let decl = FnDecl { ident: specifier.local.clone(), declare: false, function: Box::new(function) };
+        Some(ModuleItem::Stmt(Stmt::Decl(Decl::Fn(decl))))
+    }
+
+    fn is_import_meta_url(&self, expr: &Expr) -> bool {
+        if let Expr::Member(MemberExpr { obj, prop: MemberProp::Ident(prop), .. }) = expr {
+            if let Expr::MetaProp(MetaPropExpr { kind: MetaPropKind::ImportMeta, .. }) = &**obj {
+                return &*prop.sym == "url";
+            }
+        }
+        false
+    }
+
+    /// Returns the specifier if it's `new URL('./foo', import.meta.url)`, the absolute
+    /// urls such as `https://example.com/foo.js` or `data:...` are not assets.
+    fn asset_url<'b>(&self, expr: &'b mut Expr) -> Option<&'b mut Str> {
+        let Expr::New(n) = expr else { return None };
+        if !self.is_callee(n, "URL") { return None; }
+        let args = n.args.as_mut()?;
+        if args.len() != 2 || !self.is_import_meta_url(&args[1].expr) { return None; }
+        match &mut *args[0].expr {
+            Expr::Lit(Lit::Str(specifier)) if !is_absolute_url(&specifier.value) => Some(specifier),
+            _ => None,
+        }
+    }
+
+    fn add_asset(&mut self, specifier: &mut Str, kind: AssetKind) {
+        let value = specifier.value.to_string();
+        if let Some(url) = self.manifest.get(&value) {
+            *specifier = Str { span: specifier.span, value: url.as_str().into(), raw: None };
+        }
+        let asset = AssetReference { specifier: value, kind };
+        if !self.assets.contains(&asset) {
+            self.assets.push(asset);
+        }
+    }
+}
+
+/// `https://example.com/foo.js`, `//example.com/foo.js`, or any other url with a scheme.
+fn is_absolute_url(specifier: &str) -> bool {
+    if specifier.starts_with("//") {
+        return true;
+    }
+    let Some((scheme, _)) = specifier.split_once(':') else { return false };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+impl<'a> VisitMut for AssetVisitor<'a> {
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        for item in items.iter_mut() {
+            if let Some(worker) = self.worker_import(item) {
+                *item = worker;
+            }
+        }
+        items.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        if let Expr::New(worker) = n {
+            if let Some(kind) = self.worker_kind(worker) {
+                let args = worker.args.as_deref_mut().unwrap_or_default();
+                if let Some((ExprOrSpread { expr, .. }, rest)) = args.split_first_mut() {
+                    if let Some(specifier) = self.asset_url(expr) {
+                        self.add_asset(specifier, kind);
+                        // the options might reference assets as well
+                        rest.iter_mut().for_each(|arg| arg.visit_mut_with(self));
+                        return;
+                    }
+                }
+            }
+        }
+
+        if let Some(specifier) = self.asset_url(n) {
+            self.add_asset(specifier, AssetKind::Url);
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+}
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::{dynamic_import::DynamicImportSyntax, wasm_import::WasmImportSyntax};
+
+/// Options passed from porter through `jsc.experimental.plugins`, e.g.
+///
+/// ```json
+/// { "resolve": { "./foo": "./foo.js", "react": "react/18.2.0/index.js", "fs": false } }
+/// ```
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Config {
+    /// Import specifiers mapped to the module ids resolved by porter, or `false`
+    /// if the module is neglected in browser field.
+    pub resolve: HashMap<String, ResolvedId>,
+
+    /// Normalizes `require.async()` and `import()` into either `"import"` or
+    /// `"require.async"`, left untouched if not specified.
+    pub dynamic_import: Option<DynamicImportSyntax>,
+
+    /// Assets referenced with `new URL('./foo', import.meta.url)` mapped to the hashed
+    /// output urls in porter's manifest.
+    pub manifest: HashMap<String, String>,
+
+    /// CSS modules imported, e.g. `./button.module.css`, mapped to the exported class
+    /// names, which are inlined as string literals, see [crate::css_modules::CssModules].
+    pub css_modules: HashMap<String, HashMap<String, String>>,
+
+    /// Instantiates the `.wasm` files imported statically with either `"topLevelAwait"`
+    /// or `"promise"`, left untouched if not specified.
+    pub wasm_import: Option<WasmImportSyntax>,
+
+    /// Marks ES modules with `exports.__esModule`, reads default imports through an
+    /// interop helper, and reports whether the module is ESM in the metadata, see
+    /// [crate::interop::EsModuleInterop].
+    pub interop: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ResolvedId {
+    Id(String),
+    /// `{ "fs": false }` in browser field, only `false` is meaningful here.
+    Stub(bool),
+}
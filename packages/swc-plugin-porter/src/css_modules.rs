@@ -0,0 +1,179 @@
+use std::collections::{HashMap, HashSet};
+
+use porter_swc_common::diagnostics::error;
+use swc_core::{
+    common::Span,
+    ecma::{
+        ast::{
+            ComputedPropName, ExportNamedSpecifier, Expr, Id, ImportSpecifier, KeyValueProp, Lit, MemberExpr,
+            MemberProp, Module, ModuleDecl, ModuleExportName, ModuleItem, Prop, PropName, Str,
+        },
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+/// Replaces the class names imported from CSS modules with the ones mapped by porter,
+/// which leaves the generated module of the stylesheet out of the bundle:
+///
+/// ```js
+/// import styles, { primary } from './button.module.css';
+/// styles.button;
+/// primary;
+/// ```
+///
+/// is transformed into
+///
+/// ```js
+/// import './button.module.css';
+/// "button_a1b2c3";
+/// "primary_d4e5f6";
+/// ```
+///
+/// The import is kept so that the stylesheet is still loaded, so is the default or
+/// namespace import that is referenced otherwise, e.g. `styles[name]`. Classes missing
+/// in the mapping are reported as errors.
+pub struct CssModules<'a> {
+    css_modules: &'a HashMap<String, HashMap<String, String>>,
+    /// Default or namespace imports, with the specifier.
+    objects: HashMap<Id, String>,
+    /// Named imports, with the specifier and the class name.
+    classes: HashMap<Id, (String, String)>,
+    /// Imports that are referenced other than by class names, which have to be kept.
+    references: HashSet<Id>,
+}
+
+impl<'a> CssModules<'a> {
+    pub fn new(css_modules: &'a HashMap<String, HashMap<String, String>>) -> Self {
+        CssModules { css_modules, objects: HashMap::new(), classes: HashMap::new(), references: HashSet::new() }
+    }
+
+    fn class_name(&self, specifier: &str, name: &str, span: Span) -> Option<Str> {
+        match self.css_modules.get(specifier).and_then(|classes| classes.get(name)) {
+            Some(value) => Some(Str { span, value: value.as_str().into(), raw: None }),
+            None => {
+                let message = format!("class `{name}` is not exported by {specifier}");
+                error(span, &message);
+                None
+            }
+        }
+    }
+
+    /// `styles.foo` or `styles['foo']`
+    fn member(&self, n: &MemberExpr) -> Option<Str> {
+        let Expr::Ident(obj) = &*n.obj else { return None };
+        let specifier = self.objects.get(&obj.to_id())?;
+        let name = match &n.prop {
+            MemberProp::Ident(prop) => prop.sym.to_string(),
+            MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+                Expr::Lit(Lit::Str(prop)) => prop.value.to_string(),
+                _ => return None,
+            },
+            MemberProp::PrivateName(_) => return None,
+        };
+        self.class_name(specifier, &name, n.span)
+    }
+
+    fn collect(&mut self, n: &Module) {
+        for item in &n.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) = item else { continue };
+            let specifier = decl.src.value.to_string();
+            if decl.type_only || !self.css_modules.contains_key(&specifier) {
+                continue;
+            }
+            for s in &decl.specifiers {
+                match s {
+                    ImportSpecifier::Default(s) => {
+                        self.objects.insert(s.local.to_id(), specifier.clone());
+                    }
+                    ImportSpecifier::Namespace(s) => {
+                        self.objects.insert(s.local.to_id(), specifier.clone());
+                    }
+                    ImportSpecifier::Named(s) if s.is_type_only => {}
+                    ImportSpecifier::Named(s) => {
+                        let name = match &s.imported {
+                            Some(ModuleExportName::Ident(id)) => id.sym.to_string(),
+                            Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                            None => s.local.sym.to_string(),
+                        };
+                        if name == "default" {
+                            self.objects.insert(s.local.to_id(), specifier.clone());
+                        } else if self.class_name(&specifier, &name, s.span).is_some() {
+                            self.classes.insert(s.local.to_id(), (specifier.clone(), name));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl VisitMut for CssModules<'_> {
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        if self.css_modules.is_empty() {
+            return;
+        }
+        self.collect(n);
+        n.visit_mut_children_with(self);
+
+        for item in &mut n.body {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) = item else { continue };
+            if decl.type_only || !self.css_modules.contains_key(&*decl.src.value) {
+                continue;
+            }
+            decl.specifiers.retain(|s| {
+                let local = match s {
+                    ImportSpecifier::Default(s) => &s.local,
+                    ImportSpecifier::Namespace(s) => &s.local,
+                    ImportSpecifier::Named(s) => &s.local,
+                };
+                let id = local.to_id();
+                // the named imports of missing classes are kept as is
+                let rewritten = self.objects.contains_key(&id) || self.classes.contains_key(&id);
+                !rewritten || self.references.contains(&id)
+            });
+        }
+    }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        match n {
+            Expr::Ident(id) => {
+                let id = id.clone();
+                if let Some((specifier, name)) = self.classes.get(&id.to_id()) {
+                    if let Some(value) = self.class_name(specifier, name, id.span) {
+                        *n = Expr::Lit(Lit::Str(value));
+                    }
+                } else if self.objects.contains_key(&id.to_id()) {
+                    self.references.insert(id.to_id());
+                }
+            }
+            Expr::Member(member) => match self.member(member) {
+                Some(value) => *n = Expr::Lit(Lit::Str(value)),
+                None => n.visit_mut_children_with(self),
+            },
+            _ => n.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_prop(&mut self, n: &mut Prop) {
+        if let Prop::Shorthand(id) = n {
+            let id = id.clone();
+            if let Some((specifier, name)) = self.classes.get(&id.to_id()) {
+                if let Some(value) = self.class_name(specifier, name, id.span) {
+                    let value = Box::new(Expr::Lit(Lit::Str(value)));
+                    *n = Prop::KeyValue(KeyValueProp { key: PropName::Ident(id), value });
+                }
+            } else if self.objects.contains_key(&id.to_id()) {
+                self.references.insert(id.to_id());
+            }
+            return;
+        }
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_export_named_specifier(&mut self, n: &mut ExportNamedSpecifier) {
+        // `export { styles }` needs the import
+        if let ModuleExportName::Ident(id) = &n.orig {
+            self.references.insert(id.to_id());
+        }
+    }
+}
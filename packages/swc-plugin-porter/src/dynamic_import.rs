@@ -0,0 +1,223 @@
+use std::path::Path;
+
+use porter_swc_common::{
+    ast::{arrow, call, dynamic_import, ident_expr, member, member_chain, new_expr, str_lit},
+    glob::{escape, glob},
+};
+use serde::Deserialize;
+use swc_core::{
+    common::{Span, DUMMY_SP},
+    ecma::{
+        ast::{
+            BinExpr, BinaryOp, CallExpr, Callee, ComputedPropName, CondExpr, Expr, ExprOrSpread, KeyValueProp, Lit,
+            MemberExpr, MemberProp, NewExpr, ObjectLit, ParenExpr, Prop, PropName, PropOrSpread, Str, Tpl,
+        },
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+use crate::util::{call_kind, CallKind};
+
+/// The syntax that dynamic imports are normalized into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum DynamicImportSyntax {
+    /// `import('./foo')`
+    #[serde(rename = "import")]
+    Import,
+    /// `require.async('./foo', callback)`
+    #[serde(rename = "require.async")]
+    RequireAsync,
+}
+
+/// Rewrites `require.async('./foo')` and `import('./foo')` into one another, since both
+/// are treated as dynamic imports by porter.
+///
+/// Specifiers that are not string literals but have a static prefix, such as
+/// `import(`./locales/${lang}.json`)`, are expanded into a context map backed by glob:
+///
+/// ```js
+/// ((map, key) => Object.prototype.hasOwnProperty.call(map, key)
+///   ? map[key]()
+///   : Promise.reject(new Error("Cannot find module '" + key + "'")))({
+///   "./locales/en.json": () => import("./locales/en.json"),
+///   "./locales/zh.json": () => import("./locales/zh.json"),
+/// }, `./locales/${lang}.json`)
+/// ```
+///
+/// `require.async(['./foo', './bar'], callback)` is left as is because there isn't an
+/// equivalent dynamic import without spreading the results of `Promise.all()`.
+pub struct DynamicImportNormalizer<'a> {
+    filepath: &'a str,
+    syntax: DynamicImportSyntax,
+}
+
+impl<'a> DynamicImportNormalizer<'a> {
+    pub fn new(filepath: &'a str, syntax: DynamicImportSyntax) -> Self {
+        DynamicImportNormalizer { filepath, syntax }
+    }
+
+    /// `import('./foo')` or `new Promise(resolve => require.async('./foo', resolve))`
+    fn dynamic_import(&self, span: Span, specifier: Expr) -> Expr {
+        let mut expr = match self.syntax {
+            DynamicImportSyntax::Import => dynamic_import(specifier),
+            DynamicImportSyntax::RequireAsync => {
+                let body = call(member_chain(&["require", "async"]), vec![specifier, ident_expr("resolve")]);
+                new_expr(ident_expr("Promise"), vec![arrow(&["resolve"], body)])
+            }
+        };
+        match &mut expr {
+            Expr::Call(CallExpr { span: expr_span, .. }) | Expr::New(NewExpr { span: expr_span, .. }) => *expr_span = span,
+            _ => {}
+        }
+        expr
+    }
+
+    /// Turns template literals or string concatenations into glob pattern, returns
+    /// `None` if the specifier has no static prefix to glob with.
+    fn glob_pattern(&self, expr: &Expr) -> Option<String> {
+        let mut parts = Vec::new();
+        match expr {
+            Expr::Tpl(Tpl { quasis, .. }) => {
+                for (i, quasi) in quasis.iter().enumerate() {
+                    if i > 0 { parts.push("*".to_string()); }
+                    let value = quasi.cooked.as_ref().unwrap_or(&quasi.raw);
+                    parts.push(escape(value));
+                }
+            }
+            Expr::Bin(BinExpr { op: BinaryOp::Add, .. }) => self.flatten_concat(expr, &mut parts)?,
+            _ => return None,
+        };
+        let pattern = parts.concat();
+        if !pattern.starts_with("./") && !pattern.starts_with("../") { return None; }
+        // adjacent expressions like `${dir}${file}` would glob the whole directory tree
+        if pattern.contains("**") { return None; }
+        Some(pattern)
+    }
+
+    fn flatten_concat(&self, expr: &Expr, parts: &mut Vec<String>) -> Option<()> {
+        match expr {
+            Expr::Bin(BinExpr { op: BinaryOp::Add, left, right, .. }) => {
+                self.flatten_concat(left, parts)?;
+                self.flatten_concat(right, parts)
+            }
+            Expr::Lit(Lit::Str(lit)) => {
+                parts.push(escape(&lit.value));
+                Some(())
+            }
+            // the leftmost operand must be string to make sure it's concatenation
+            _ if parts.is_empty() => None,
+            _ => {
+                parts.push("*".to_string());
+                Some(())
+            }
+        }
+    }
+
+    fn context_map(&self, pattern: &str) -> ObjectLit {
+        let base = Path::new(self.filepath).parent().unwrap();
+        let mut props = Vec::new();
+        for path in glob(base, pattern) {
+            let filename = path.strip_prefix(base).unwrap().to_str().unwrap();
+            let specifier = if filename.starts_with('.') {
+                filename.to_string()
+            } else {
+                format!("./{filename}")
+            };
+            let value = arrow(&[], self.dynamic_import(DUMMY_SP, str_lit(&specifier)));
+            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Str(Str::from(specifier)),
+                value: Box::new(value),
+            }))));
+        }
+        ObjectLit { span: DUMMY_SP, props }
+    }
+
+    /// `((map, key) => Object.prototype.hasOwnProperty.call(map, key) ? map[key]() : Promise.reject(...))`
+    /// called with the context map and the key.
+    fn context_import(&self, span: Span, pattern: &str, specifier: Box<Expr>) -> Expr {
+        let entry = Expr::Member(MemberExpr {
+            span: DUMMY_SP,
+            obj: Box::new(ident_expr("map")),
+            prop: MemberProp::Computed(ComputedPropName { span: DUMMY_SP, expr: Box::new(ident_expr("key")) }),
+        });
+        let message = Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: BinaryOp::Add,
+            left: Box::new(Expr::Bin(BinExpr {
+                span: DUMMY_SP,
+                op: BinaryOp::Add,
+                left: Box::new(str_lit("Cannot find module '")),
+                right: Box::new(ident_expr("key")),
+            })),
+            right: Box::new(str_lit("'")),
+        });
+        let error = new_expr(ident_expr("Error"), vec![message]);
+        let body = Expr::Cond(CondExpr {
+            span: DUMMY_SP,
+            test: Box::new(call(
+                member_chain(&["Object", "prototype", "hasOwnProperty", "call"]),
+                vec![ident_expr("map"), ident_expr("key")],
+            )),
+            cons: Box::new(call(entry, Vec::new())),
+            alt: Box::new(call(member_chain(&["Promise", "reject"]), vec![error])),
+        });
+        let callee = arrow(&["map", "key"], body);
+        Expr::Call(CallExpr {
+            span,
+            callee: Callee::Expr(Box::new(Expr::Paren(ParenExpr {
+                span: DUMMY_SP,
+                expr: Box::new(callee),
+            }))),
+            args: vec![
+                ExprOrSpread { spread: None, expr: Box::new(Expr::Object(self.context_map(pattern))) },
+                ExprOrSpread { spread: None, expr: specifier },
+            ],
+            type_args: None,
+        })
+    }
+
+    fn normalize(&self, n: &mut CallExpr, kind: CallKind) -> Option<Expr> {
+        let specifier = n.args.first()?;
+        if specifier.spread.is_some() { return None; }
+
+        if !matches!(&*specifier.expr, Expr::Lit(Lit::Str(_))) {
+            let pattern = self.glob_pattern(&specifier.expr)?;
+            let expr = self.context_import(n.span, &pattern, specifier.expr.clone());
+            return Some(match n.args.get(1) {
+                Some(callback) if kind == CallKind::RequireAsync => {
+                    call(member(expr, "then"), vec![*callback.expr.clone()])
+                }
+                _ => expr,
+            });
+        }
+
+        match (kind, self.syntax) {
+            // require.async('./foo', callback) => import('./foo').then(callback)
+            (CallKind::RequireAsync, DynamicImportSyntax::Import) => {
+                let expr = self.dynamic_import(n.span, *specifier.expr.clone());
+                Some(match n.args.get(1) {
+                    Some(callback) => call(member(expr, "then"), vec![*callback.expr.clone()]),
+                    None => expr,
+                })
+            }
+            (CallKind::Import, DynamicImportSyntax::RequireAsync) => {
+                Some(self.dynamic_import(n.span, *specifier.expr.clone()))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> VisitMut for DynamicImportNormalizer<'a> {
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        n.visit_mut_children_with(self);
+
+        if let Expr::Call(call) = n {
+            if let Some(kind @ (CallKind::Import | CallKind::RequireAsync)) = call_kind(call) {
+                if let Some(expr) = self.normalize(call, kind) {
+                    *n = expr;
+                }
+            }
+        }
+    }
+}
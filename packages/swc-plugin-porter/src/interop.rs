@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+
+use porter_swc_common::ast::{binding, call, expr_stmt, ident, ident_expr, member, member_chain, str_lit};
+use swc_core::{
+    common::DUMMY_SP,
+    ecma::{
+        ast::{
+            BinExpr, BinaryOp, BlockStmt, CallExpr, CondExpr, Decl, Expr, FnDecl, Function, Id, Ident,
+            ImportSpecifier, KeyValueProp, Lit, Module, ModuleDecl, ModuleItem, ObjectLit, Param, Prop, PropName,
+            PropOrSpread, ReturnStmt, Stmt,
+        },
+        visit::{Visit, VisitMut, VisitMutWith, VisitWith},
+    },
+};
+
+use crate::util::{call_kind, CallKind};
+
+const INTEROP_DEFAULT: &str = "_porter_interop_default";
+
+/// Tells whether the module is written in ESM syntax, which is the same check as the
+/// `__esModule` flag of ImportVisitor in porter.
+#[derive(Default)]
+pub struct EsModuleDetector {
+    pub es_module: bool,
+}
+
+impl Visit for EsModuleDetector {
+    fn visit_module_decl(&mut self, n: &ModuleDecl) {
+        // `import type` and `export type` are gone after TypeScript is stripped
+        match n {
+            ModuleDecl::Import(decl) if decl.type_only => {}
+            ModuleDecl::ExportNamed(decl) if decl.type_only => {}
+            ModuleDecl::ExportAll(decl) if decl.type_only => {}
+            ModuleDecl::TsImportEquals(_) | ModuleDecl::TsExportAssignment(_) | ModuleDecl::TsNamespaceExport(_) => {}
+            _ => self.es_module = true,
+        }
+    }
+
+    fn visit_call_expr(&mut self, n: &CallExpr) {
+        if call_kind(n) == Some(CallKind::Import) {
+            self.es_module = true;
+        }
+        n.visit_children_with(self);
+    }
+}
+
+/// Marks ES modules with `exports.__esModule` and reads default imports through an
+/// interop helper, which mirrors how webpack treats mixed packages in node_modules:
+///
+/// ```js
+/// import React, { useState } from 'react';
+/// React.createElement('div');
+/// ```
+///
+/// is transformed into
+///
+/// ```js
+/// import React, { useState } from 'react';
+/// Object.defineProperty(exports, "__esModule", { value: true });
+/// function _porter_interop_default(m) { return m && m.__esModule ? m.default : m; }
+/// _porter_interop_default(React).createElement('div');
+/// ```
+///
+/// hence `React` is `module.exports` of CommonJS modules and the default export of
+/// modules that carry the marker. The import declarations are left as they are, so are
+/// the live bindings, which requires the CommonJS transform that follows to import the
+/// default as `module.exports`, i.e. `importInterop: "node"` of swc, otherwise the
+/// default export is unwrapped twice.
+///
+/// The references are told apart by their syntax context, which requires the program
+/// to be resolved first, the same as the programs passed to wasm plugins. JSX element
+/// names are left untouched since they can't be call expressions.
+#[derive(Default)]
+pub struct EsModuleInterop {
+    locals: HashSet<Id>,
+}
+
+impl EsModuleInterop {
+    /// `Object.defineProperty(exports, "__esModule", { value: true });`
+    fn marker(&self) -> ModuleItem {
+        let value = KeyValueProp { key: PropName::Ident(ident("value")), value: Box::new(Expr::Lit(Lit::Bool(true.into()))) };
+        let descriptor = Expr::Object(ObjectLit {
+            span: DUMMY_SP,
+            props: vec![PropOrSpread::Prop(Box::new(Prop::KeyValue(value)))],
+        });
+        let args = vec![ident_expr("exports"), str_lit("__esModule"), descriptor];
+        ModuleItem::Stmt(expr_stmt(call(member_chain(&["Object", "defineProperty"]), args)))
+    }
+
+    /// `function _porter_interop_default(m) { return m && m.__esModule ? m.default : m; }`
+    fn helper(&self) -> ModuleItem {
+        let m = || ident_expr("m");
+        let test = Expr::Bin(BinExpr {
+            span: DUMMY_SP,
+            op: BinaryOp::LogicalAnd,
+            left: Box::new(m()),
+            right: Box::new(member(m(), "__esModule")),
+        });
+        let result = Expr::Cond(CondExpr {
+            span: DUMMY_SP,
+            test: Box::new(test),
+            cons: Box::new(member(m(), "default")),
+            alt: Box::new(m()),
+        });
+        ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl {
+            ident: ident(INTEROP_DEFAULT),
+            declare: false,
+            function: Box::new(Function {
+                params: vec![Param {
+                    span: DUMMY_SP,
+                    decorators: Vec::new(),
+                    pat: binding(ident("m")),
+                }],
+                decorators: Vec::new(),
+                span: DUMMY_SP,
+                body: Some(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts: vec![Stmt::Return(ReturnStmt { span: DUMMY_SP, arg: Some(Box::new(result)) })],
+                }),
+                is_generator: false,
+                is_async: false,
+                type_params: None,
+                return_type: None,
+            }),
+        })))
+    }
+
+    /// `_porter_interop_default(foo)`
+    fn interop_default(&self, local: &Ident) -> Expr {
+        call(ident_expr(INTEROP_DEFAULT), vec![Expr::Ident(local.clone())])
+    }
+}
+
+impl VisitMut for EsModuleInterop {
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        for item in n.body.iter() {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) = item {
+                if decl.type_only { continue; }
+                for specifier in decl.specifiers.iter() {
+                    if let ImportSpecifier::Default(specifier) = specifier {
+                        self.locals.insert(specifier.local.to_id());
+                    }
+                }
+            }
+        }
+        let mut items = vec![self.marker()];
+        if !self.locals.is_empty() {
+            n.visit_mut_children_with(self);
+            items.push(self.helper());
+        }
+        // right after the import declarations
+        let index = n.body.iter().rposition(|item| matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_))));
+        let index = index.map_or(0, |index| index + 1);
+        n.body.splice(index..index, items);
+    }
+
+    fn visit_mut_module_decl(&mut self, n: &mut ModuleDecl) {
+        // `export { foo }` refers to the binding rather than the value
+        if !matches!(n, ModuleDecl::Import(_) | ModuleDecl::ExportNamed(_)) {
+            n.visit_mut_children_with(self);
+        }
+    }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        if let Expr::Ident(id) = n {
+            if self.locals.contains(&id.to_id()) {
+                *n = self.interop_default(id);
+            }
+            return;
+        }
+        n.visit_mut_children_with(self);
+    }
+
+    /// `{ foo }` into `{ foo: _porter_interop_default(foo) }`
+    fn visit_mut_prop(&mut self, n: &mut Prop) {
+        if let Prop::Shorthand(id) = n {
+            if self.locals.contains(&id.to_id()) {
+                *n = Prop::KeyValue(KeyValueProp {
+                    key: PropName::Ident(id.clone()),
+                    value: Box::new(self.interop_default(id)),
+                });
+            }
+            return;
+        }
+        n.visit_mut_children_with(self);
+    }
+}
@@ -1,33 +1,113 @@
+use porter_swc_common::ast::member_chain;
 use swc_core::{ecma::{
-    ast::{Program, MemberExpr, Expr, Ident, VarDeclarator, MemberProp},
+    ast::{Module, Script, MemberExpr, Expr, VarDeclarator},
     transforms::testing::test,
-    visit::{as_folder, FoldWith, VisitMut, VisitMutWith},
-}, common::DUMMY_SP};
-use swc_core::plugin::{plugin_transform, proxies::TransformPluginProgramMetadata};
+    visit::{VisitMut, VisitMutWith, VisitWith},
+}, common::{comments::Comments, Spanned}};
 
-pub fn porter_transform() -> impl VisitMut {
-    PorterVisitor
+pub use self::config::*;
+pub use self::metadata::*;
+pub mod asset;
+pub mod config;
+pub mod css_modules;
+pub mod dynamic_import;
+pub mod interop;
+pub mod metadata;
+pub mod resolve;
+mod util;
+pub mod wasm_import;
+
+use self::{
+    asset::AssetVisitor,
+    css_modules::CssModules,
+    dynamic_import::DynamicImportNormalizer,
+    interop::{EsModuleDetector, EsModuleInterop},
+    resolve::ImportResolver,
+    wasm_import::WasmImport,
+};
+
+pub fn porter_transform<C>(filepath: String, config: Config, comments: C) -> PorterVisitor<C>
+where
+    C: Comments,
+{
+    PorterVisitor { filepath, config, comments, metadata: Default::default() }
 }
 
-pub struct PorterVisitor;
+pub struct PorterVisitor<C>
+where
+    C: Comments,
+{
+    filepath: String,
+    config: Config,
+    comments: C,
+    metadata: Metadata,
+}
+
+impl<C> PorterVisitor<C>
+where
+    C: Comments,
+{
+    /// Assets and such collected from the transformed module.
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
 
-impl PorterVisitor {
     fn module_meta(&self) -> Expr {
-        let obj = Expr::Ident(Ident{ span: DUMMY_SP, sym: "require".into(), optional: false });
-        let prop = MemberProp::Ident(Ident { span: DUMMY_SP, sym: "meta".into(), optional: false });
-        Expr::Member(MemberExpr { span: DUMMY_SP, obj: Box::new(obj), prop })
+        member_chain(&["require", "meta"])
+    }
+
+    /// Runs the transforms enabled in config, before `import.meta` gets replaced.
+    fn visit_mut_passes<N>(&mut self, n: &mut N)
+    where
+        N: Spanned + VisitWith<EsModuleDetector> + for<'a> VisitMutWith<dyn VisitMut + 'a>,
+    {
+        let Config { resolve, dynamic_import, manifest, css_modules, wasm_import, interop } = &self.config;
+        if *interop {
+            let mut detector = EsModuleDetector::default();
+            n.visit_with(&mut detector);
+            self.metadata.es_module = detector.es_module;
+        }
+        if let Some(syntax) = dynamic_import {
+            n.visit_mut_with(&mut DynamicImportNormalizer::new(&self.filepath, *syntax) as &mut dyn VisitMut);
+        }
+        // the urls of the wasm files are reported as assets
+        if let Some(syntax) = wasm_import {
+            n.visit_mut_with(&mut WasmImport::new(&self.filepath, *syntax) as &mut dyn VisitMut);
+        }
+        n.visit_mut_with(&mut AssetVisitor::new(manifest, &mut self.metadata.assets) as &mut dyn VisitMut);
+        // the mapping is keyed by the specifiers before they are resolved
+        n.visit_mut_with(&mut CssModules::new(css_modules) as &mut dyn VisitMut);
+        n.visit_mut_with(&mut ImportResolver::new(resolve) as &mut dyn VisitMut);
+        self.metadata.emit(&self.comments, n.span().hi);
     }
 }
 
-impl VisitMut for PorterVisitor {
+impl<C> VisitMut for PorterVisitor<C>
+where
+    C: Comments,
+{
     // Implement necessary visit_mut_* methods for actual custom transform.
     // A comprehensive list of possible visitor methods can be found here:
     // https://rustdoc.swc.rs/swc_ecma_visit/trait.VisitMut.html
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        self.visit_mut_passes(n);
+        // scripts can't have import declarations, `import()` alone is left to the loader
+        if self.config.interop && self.metadata.es_module {
+            n.visit_mut_with(&mut EsModuleInterop::default());
+        }
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_script(&mut self, n: &mut Script) {
+        self.visit_mut_passes(n);
+        n.visit_mut_children_with(self);
+    }
+
     fn visit_mut_member_expr(&mut self, n: &mut MemberExpr) {
         n.visit_mut_children_with(self);
 
         if let Expr::MetaProp(_obj) = &*n.obj {
-            n.obj = Box::new(self.module_meta());
+            *n.obj = self.module_meta();
         }
     }
 
@@ -42,33 +122,13 @@ impl VisitMut for PorterVisitor {
     }
 }
 
-/// An example plugin function with macro support.
-/// `plugin_transform` macro interop pointers into deserialized structs, as well
-/// as returning ptr back to host.
-///
-/// It is possible to opt out from macro by writing transform fn manually
-/// if plugin need to handle low-level ptr directly via
-/// `__transform_plugin_process_impl(
-///     ast_ptr: *const u8, ast_ptr_len: i32,
-///     unresolved_mark: u32, should_enable_comments_proxy: i32) ->
-///     i32 /*  0 for success, fail otherwise.
-///             Note this is only for internal pointer interop result,
-///             not actual transform result */`
-///
-/// This requires manual handling of serialization / deserialization from ptrs.
-/// Refer swc_plugin_macro to see how does it work internally.
-#[plugin_transform]
-pub fn process_transform(program: Program, _metadata: TransformPluginProgramMetadata) -> Program {
-    program.fold_with(&mut as_folder(PorterVisitor))
-}
-
 // An example to test plugin transform.
 // Recommended strategy to test plugin's transform is verify
 // the Visitor's behavior, instead of trying to run `process_transform` with mocks
 // unless explicitly required to do so.
 test!(
     Default::default(),
-    |_| as_folder(PorterVisitor),
+    |t| swc_core::ecma::visit::as_folder(porter_transform(String::new(), Default::default(), t.comments.clone())),
     boo,
     // Input codes
     r#"console.log("transform");"#,
@@ -0,0 +1,57 @@
+use serde::Serialize;
+use swc_core::common::{
+    comments::{Comment, CommentKind, Comments},
+    BytePos, DUMMY_SP,
+};
+
+/// Collected while transforming, which is reported to porter in a trailing comment
+/// since wasm plugins can only return the transformed program:
+///
+/// ```js
+/// //# porterMetadata={"assets":[{"specifier":"./worker.js","kind":"worker"}]}
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Metadata {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub assets: Vec<AssetReference>,
+    /// Whether the module is written in ESM syntax, named after the flag in porter's
+    /// module cache.
+    #[serde(rename = "__esModule", skip_serializing_if = "std::ops::Not::not")]
+    pub es_module: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetReference {
+    pub specifier: String,
+    pub kind: AssetKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AssetKind {
+    /// `new URL('./foo.wasm', import.meta.url)`
+    Url,
+    /// `new Worker(new URL('./worker.js', import.meta.url))` or `import W from './worker.js?worker'`
+    Worker,
+    /// `new SharedWorker(new URL('./worker.js', import.meta.url))` or
+    /// `import W from './worker.js?sharedworker'`
+    SharedWorker,
+}
+
+impl Metadata {
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty() && !self.es_module
+    }
+
+    pub fn emit<C: Comments>(&self, comments: &C, pos: BytePos) {
+        if self.is_empty() { return; }
+        let json = serde_json::to_string(self).expect("failed to serialize metadata");
+        comments.add_trailing(pos, Comment {
+            kind: CommentKind::Line,
+            span: DUMMY_SP,
+            text: format!("# porterMetadata={json}").into(),
+        });
+    }
+}
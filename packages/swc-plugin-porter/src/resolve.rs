@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+
+use porter_swc_common::ast::{call, ident, ident_expr, member_chain, var_decl};
+use swc_core::{
+    common::{util::take::Take, DUMMY_SP},
+    ecma::{
+        ast::{
+            ExportDecl, ExportNamedSpecifier, ExportSpecifier, Expr, ExprOrSpread, ImportSpecifier, Lit,
+            ModuleDecl, ModuleExportName, ModuleItem, NamedExport, ObjectLit, Stmt, Str, VarDeclKind,
+        },
+        visit::{VisitMut, VisitMutWith},
+    },
+};
+
+use crate::{config::ResolvedId, util::{call_kind, CallKind}};
+
+/// Rewrites import specifiers to the module ids resolved by porter, which replaces
+/// the specifier resolution that used to happen after parsing in TypeScript.
+///
+/// Modules that are neglected with `false` are replaced with the empty stub, which
+/// is an empty object just like what the loader returns for `require('fs')`.
+pub struct ImportResolver<'a> {
+    resolve: &'a HashMap<String, ResolvedId>,
+}
+
+impl<'a> ImportResolver<'a> {
+    pub fn new(resolve: &'a HashMap<String, ResolvedId>) -> Self {
+        ImportResolver { resolve }
+    }
+
+    fn lookup(&self, specifier: &str) -> Option<&ResolvedId> {
+        match self.resolve.get(specifier) {
+            Some(ResolvedId::Stub(true)) => None,
+            result => result,
+        }
+    }
+
+    fn resolve_str(&self, src: &mut Str) -> bool {
+        match self.lookup(&src.value) {
+            Some(ResolvedId::Id(id)) => {
+                *src = Str { span: src.span, value: id.as_str().into(), raw: None };
+                true
+            }
+            Some(ResolvedId::Stub(_)) => false,
+            None => true,
+        }
+    }
+
+    fn empty_object(&self) -> Expr {
+        Expr::Object(ObjectLit { span: DUMMY_SP, props: Vec::new() })
+    }
+
+    /// `Promise.resolve({})`
+    fn empty_promise(&self) -> Expr {
+        call(member_chain(&["Promise", "resolve"]), vec![self.empty_object()])
+    }
+
+    /// `import foo, { bar } from 'fs'` => `const foo = {}, bar = undefined;`
+    fn stub_import(&self, specifiers: &[ImportSpecifier]) -> Option<ModuleItem> {
+        if specifiers.is_empty() { return None; }
+        let decls = specifiers.iter().map(|specifier| match specifier {
+            ImportSpecifier::Default(s) => (s.local.clone(), Some(self.empty_object())),
+            ImportSpecifier::Namespace(s) => (s.local.clone(), Some(self.empty_object())),
+            ImportSpecifier::Named(s) => (s.local.clone(), Some(ident_expr("undefined"))),
+        }).collect();
+        Some(ModuleItem::Stmt(var_decl(VarDeclKind::Const, decls)))
+    }
+
+    /// `export { default as foo, bar } from 'fs'` => `export const foo = {}, bar = undefined;`,
+    /// which keeps the exports of the module. The ones exported as `default` or string names
+    /// can't be declared directly, hence they are exported from stub locals instead.
+    fn stub_export(&self, specifiers: &[ExportSpecifier], index: &mut usize) -> Vec<ModuleItem> {
+        let mut decls = Vec::new();
+        let mut locals = Vec::new();
+        for specifier in specifiers {
+            let (orig, exported) = match specifier {
+                ExportSpecifier::Named(s) => (Some(&s.orig), s.exported.as_ref().unwrap_or(&s.orig)),
+                ExportSpecifier::Namespace(s) => (None, &s.name),
+                ExportSpecifier::Default(_) => continue,
+            };
+            let value = match orig {
+                Some(ModuleExportName::Ident(id)) if &*id.sym != "default" => ident_expr("undefined"),
+                Some(ModuleExportName::Str(s)) if &*s.value != "default" => ident_expr("undefined"),
+                _ => self.empty_object(),
+            };
+            match exported {
+                ModuleExportName::Ident(id) if &*id.sym != "default" => decls.push((id.clone(), Some(value))),
+                _ => {
+                    let local = ident(&format!("__porter_stub_{index}"));
+                    *index += 1;
+                    locals.push((local, value, exported.clone()));
+                }
+            }
+        }
+        let mut items = Vec::new();
+        if !decls.is_empty() {
+            let Stmt::Decl(decl) = var_decl(VarDeclKind::Const, decls) else { unreachable!() };
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span: DUMMY_SP, decl })));
+        }
+        if !locals.is_empty() {
+            let decls = locals.iter().map(|(local, value, _)| (local.clone(), Some(value.clone()))).collect();
+            items.push(ModuleItem::Stmt(var_decl(VarDeclKind::Const, decls)));
+            let specifiers = locals.into_iter().map(|(local, _, exported)| {
+                ExportSpecifier::Named(ExportNamedSpecifier {
+                    span: DUMMY_SP,
+                    orig: ModuleExportName::Ident(local),
+                    exported: Some(exported),
+                    is_type_only: false,
+                })
+            });
+            items.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                span: DUMMY_SP,
+                specifiers: specifiers.collect(),
+                src: None,
+                type_only: false,
+                asserts: None,
+            })));
+        }
+        items
+    }
+}
+
+impl<'a> VisitMut for ImportResolver<'a> {
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        items.visit_mut_children_with(self);
+
+        let mut result = Vec::with_capacity(items.len());
+        let mut index = 0;
+        for item in items.take() {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(mut decl)) => {
+                    if self.resolve_str(&mut decl.src) {
+                        result.push(ModuleItem::ModuleDecl(ModuleDecl::Import(decl)));
+                    } else if let Some(stub) = self.stub_import(&decl.specifiers) {
+                        result.push(stub);
+                    }
+                }
+                // the empty stub has no exports to be enumerated
+                ModuleItem::ModuleDecl(ModuleDecl::ExportAll(mut decl)) => {
+                    if self.resolve_str(&mut decl.src) {
+                        result.push(ModuleItem::ModuleDecl(ModuleDecl::ExportAll(decl)));
+                    }
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(mut decl)) => {
+                    let keep = match &mut decl.src {
+                        Some(src) => self.resolve_str(src),
+                        None => true,
+                    };
+                    if keep {
+                        result.push(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(decl)));
+                    } else {
+                        result.extend(self.stub_export(&decl.specifiers, &mut index));
+                    }
+                }
+                _ => result.push(item),
+            }
+        }
+        *items = result;
+    }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        n.visit_mut_children_with(self);
+
+        let Expr::Call(call) = n else { return };
+        let Some(kind) = call_kind(call) else { return };
+        let Some(ExprOrSpread { expr, .. }) = call.args.first_mut() else { return };
+        let Expr::Lit(Lit::Str(src)) = &mut **expr else { return };
+
+        if self.resolve_str(src) { return; }
+        match kind {
+            CallKind::Require => *n = self.empty_object(),
+            CallKind::Import => *n = self.empty_promise(),
+            // the loader returns empty object for neglected modules already
+            CallKind::RequireAsync => {}
+        }
+    }
+}
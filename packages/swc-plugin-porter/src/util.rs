@@ -0,0 +1,30 @@
+use swc_core::ecma::ast::{CallExpr, Callee, Expr, MemberExpr, MemberProp};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CallKind {
+    /// `require('./foo')`
+    Require,
+    /// `require.async('./foo', callback)`
+    RequireAsync,
+    /// `import('./foo')`
+    Import,
+}
+
+pub(crate) fn call_kind(n: &CallExpr) -> Option<CallKind> {
+    match &n.callee {
+        Callee::Import(_) => Some(CallKind::Import),
+        Callee::Expr(callee) => match &**callee {
+            Expr::Ident(id) if &*id.sym == "require" => Some(CallKind::Require),
+            Expr::Member(MemberExpr { obj, prop: MemberProp::Ident(prop), .. }) => {
+                if let Expr::Ident(id) = &**obj {
+                    if &*id.sym == "require" && &*prop.sym == "async" {
+                        return Some(CallKind::RequireAsync);
+                    }
+                }
+                None
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,436 @@
+use std::{fs, path::Path};
+
+use porter_swc_common::{
+    ast::{
+        arrow_with, binding, call, expr_stmt, ident, ident_expr, import_decl, member, member_chain,
+        namespace_specifier, new_url, var_decl,
+    },
+    diagnostics::error,
+};
+use serde::Deserialize;
+use swc_core::{
+    common::{Span, DUMMY_SP},
+    ecma::{
+        ast::{
+            ArrayLit, ArrowExpr, AssignExpr, AssignOp, AwaitExpr, BlockStmt, BlockStmtOrExpr, Decl, ExportDecl,
+            Expr, ExprOrSpread, Function, Id, Ident, ImportDecl, ImportSpecifier, KeyValueProp, MemberProp, Module,
+            ModuleDecl, ModuleExportName, ModuleItem, ObjectLit, ParenExpr, PatOrExpr, Prop, PropName, PropOrSpread,
+            Stmt, Str, VarDeclKind,
+        },
+        visit::{Visit, VisitMut, VisitWith},
+    },
+};
+
+/// How the imported `.wasm` files are waited for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum WasmImportSyntax {
+    /// `await WebAssembly.instantiateStreaming()` at the top level of the module.
+    #[serde(rename = "topLevelAwait")]
+    TopLevelAwait,
+    /// `WebAssembly.instantiateStreaming().then()`, the imported bindings are `undefined`
+    /// until the instantiation settles, which is exported as `__wasm_ready`. The bindings
+    /// can't be referenced at the top level of the module hence.
+    #[serde(rename = "promise")]
+    Promise,
+}
+
+/// The module names in the import section and the names in the export section.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WasmSections {
+    pub imports: Vec<String>,
+    pub exports: Vec<String>,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Reader<'_> {
+    fn byte(&mut self) -> Result<u8, String> {
+        let byte = *self.bytes.get(self.pos).ok_or("unexpected end of wasm")?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn leb128(&mut self) -> Result<u64, String> {
+        let mut result = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.byte()?;
+            result |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+            if shift >= 64 {
+                return Err("invalid leb128 in wasm".to_string());
+            }
+        }
+    }
+
+    fn name(&mut self) -> Result<String, String> {
+        let len = self.leb128()? as usize;
+        let bytes = self.bytes.get(self.pos..self.pos + len).ok_or("unexpected end of wasm")?;
+        self.pos += len;
+        String::from_utf8(bytes.to_vec()).map_err(|_| "invalid utf8 name in wasm".to_string())
+    }
+
+    fn limits(&mut self) -> Result<(), String> {
+        let flags = self.byte()?;
+        self.leb128()?;
+        if flags & 1 != 0 {
+            self.leb128()?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the import and export sections of the wasm binary, the rest is skipped.
+pub fn wasm_sections(bytes: &[u8]) -> Result<WasmSections, String> {
+    if !bytes.starts_with(b"\0asm") || bytes.len() < 8 {
+        return Err("not a wasm binary".to_string());
+    }
+    let mut reader = Reader { bytes, pos: 8 };
+    let mut sections = WasmSections::default();
+    while reader.pos < bytes.len() {
+        let id = reader.byte()?;
+        let size = reader.leb128()? as usize;
+        let end = reader.pos + size;
+        match id {
+            // import section
+            2 => {
+                for _ in 0..reader.leb128()? {
+                    let module = reader.name()?;
+                    reader.name()?;
+                    match reader.byte()? {
+                        // function or tag
+                        0 => { reader.leb128()?; }
+                        4 => { reader.byte()?; reader.leb128()?; }
+                        // table
+                        1 => { reader.byte()?; reader.limits()?; }
+                        // memory
+                        2 => reader.limits()?,
+                        // global
+                        3 => { reader.byte()?; reader.byte()?; }
+                        kind => return Err(format!("unknown import kind {kind} in wasm")),
+                    }
+                    if !sections.imports.contains(&module) {
+                        sections.imports.push(module);
+                    }
+                }
+            }
+            // export section
+            7 => {
+                for _ in 0..reader.leb128()? {
+                    sections.exports.push(reader.name()?);
+                    reader.byte()?;
+                    reader.leb128()?;
+                }
+            }
+            _ => {}
+        }
+        reader.pos = end;
+    }
+    Ok(sections)
+}
+
+/// The specifier of the module imported by the wasm file, which is relative to the wasm
+/// file, made relative to the module importing the wasm file.
+fn relative_to(wasm: &str, module: &str) -> String {
+    if !module.starts_with("./") && !module.starts_with("../") {
+        return module.to_string();
+    }
+    let mut parts: Vec<&str> = wasm.split('/').collect();
+    parts.pop();
+    for part in module.split('/') {
+        match part {
+            "." => {}
+            ".." => match parts.last() {
+                Some(&last) if last != "." && last != ".." => { parts.pop(); }
+                Some(&".") => {
+                    parts.pop();
+                    parts.push("..");
+                }
+                _ => parts.push(".."),
+            },
+            part => parts.push(part),
+        }
+    }
+    let specifier = parts.join("/");
+    if specifier.starts_with("./") || specifier.starts_with("../") {
+        specifier
+    } else {
+        format!("./{specifier}")
+    }
+}
+
+/// Instantiates the `.wasm` files imported statically, with the modules imported by the
+/// wasm file passed in as the import object, which is how the ESM integration of
+/// WebAssembly works:
+///
+/// ```js
+/// import { greet } from './pkg/hello_wasm_bg.wasm';
+/// ```
+///
+/// is transformed into
+///
+/// ```js
+/// import * as __wasm_imports_0_0 from "./pkg/hello_wasm_bg.js";
+/// const __wasm_0 = (await WebAssembly.instantiateStreaming(fetch(new URL("./pkg/hello_wasm_bg.wasm", import.meta.url)), {
+///     "./hello_wasm_bg.js": __wasm_imports_0_0
+/// })).instance.exports;
+/// const greet = __wasm_0.greet;
+/// ```
+///
+/// The url is reported as asset and rewritten by [crate::asset::AssetVisitor] afterwards.
+/// With [WasmImportSyntax::Promise] the bindings are declared with `let` and assigned
+/// once the instantiation settles, which can be waited for by the importers:
+///
+/// ```js
+/// let greet;
+/// const __wasm_ready_0 = WebAssembly.instantiateStreaming(...).then((result) => {
+///     const __wasm_0 = result.instance.exports;
+///     greet = __wasm_0.greet;
+/// });
+/// export const __wasm_ready = Promise.all([__wasm_ready_0]);
+/// ```
+pub struct WasmImport<'a> {
+    filepath: &'a str,
+    syntax: WasmImportSyntax,
+    index: usize,
+}
+
+/// The imports of the same wasm file, with the local bindings and the names of the
+/// exports, or `None` for the namespace imports.
+struct Instance {
+    specifier: String,
+    span: Span,
+    sections: WasmSections,
+    bindings: Vec<(Ident, Option<String>)>,
+}
+
+impl<'a> WasmImport<'a> {
+    pub fn new(filepath: &'a str, syntax: WasmImportSyntax) -> Self {
+        WasmImport { filepath, syntax, index: 0 }
+    }
+
+    /// The bindings of the import, with the names of the exports, or `None` for the
+    /// namespace import.
+    fn bindings(&self, decl: &ImportDecl, sections: &WasmSections) -> Option<Vec<(Ident, Option<String>)>> {
+        let mut bindings = Vec::new();
+        for specifier in &decl.specifiers {
+            match specifier {
+                ImportSpecifier::Namespace(s) => bindings.push((s.local.clone(), None)),
+                ImportSpecifier::Named(s) => {
+                    let name = match &s.imported {
+                        Some(ModuleExportName::Ident(id)) => id.sym.to_string(),
+                        Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                        None => s.local.sym.to_string(),
+                    };
+                    if !sections.exports.contains(&name) {
+                        error(s.span, &format!("`{name}` is not exported by {}", decl.src.value));
+                        return None;
+                    }
+                    bindings.push((s.local.clone(), Some(name)));
+                }
+                ImportSpecifier::Default(s) => {
+                    error(s.span, &format!("{} has no default export", decl.src.value));
+                    return None;
+                }
+            }
+        }
+        Some(bindings)
+    }
+
+    fn sections(&self, decl: &ImportDecl) -> Option<WasmSections> {
+        let specifier = &*decl.src.value;
+        let path = Path::new(self.filepath).parent()?.join(specifier);
+        let sections = match fs::read(&path) {
+            Ok(bytes) => wasm_sections(&bytes),
+            Err(err) => Err(err.to_string()),
+        };
+        match sections {
+            Ok(sections) => Some(sections),
+            Err(message) => {
+                error(decl.span, &format!("failed to read {specifier}: {message}"));
+                None
+            }
+        }
+    }
+
+    fn instantiate(&mut self, instance: Instance) -> Vec<ModuleItem> {
+        let Instance { specifier, span, sections, bindings } = instance;
+        let specifier = specifier.as_str();
+        let index = self.index;
+        self.index += 1;
+        let mut items = Vec::new();
+        let mut props = Vec::new();
+        for (i, module) in sections.imports.iter().enumerate() {
+            let local = ident(&format!("__wasm_imports_{index}_{i}"));
+            items.push(import_decl(&relative_to(specifier, module), vec![namespace_specifier(local.clone())]));
+            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Str(Str::from(module.as_str())),
+                value: Box::new(Expr::Ident(local)),
+            }))));
+        }
+
+        // WebAssembly.instantiateStreaming(fetch(new URL("./foo.wasm", import.meta.url)), { ... })
+        let url = new_url(specifier);
+        let response = call(ident_expr("fetch"), vec![url]);
+        let instantiate = call(
+            member_chain(&["WebAssembly", "instantiateStreaming"]),
+            vec![response, Expr::Object(ObjectLit { span: DUMMY_SP, props })],
+        );
+
+        let exports = ident(&format!("__wasm_{index}"));
+        let values = bindings.iter().map(|(local, name)| {
+            let value = match name {
+                Some(name) => member(Expr::Ident(exports.clone()), name),
+                None => Expr::Ident(exports.clone()),
+            };
+            (local.clone(), value)
+        });
+        match self.syntax {
+            WasmImportSyntax::TopLevelAwait => {
+                let result = Expr::Paren(ParenExpr {
+                    span: DUMMY_SP,
+                    expr: Box::new(Expr::Await(AwaitExpr { span: DUMMY_SP, arg: Box::new(instantiate) })),
+                });
+                let init = member(member(result, "instance"), "exports");
+                items.push(ModuleItem::Stmt(var_decl(VarDeclKind::Const, vec![(exports.clone(), Some(init))])));
+                for (local, value) in values {
+                    items.push(ModuleItem::Stmt(var_decl(VarDeclKind::Const, vec![(local, Some(value))])));
+                }
+            }
+            WasmImportSyntax::Promise => {
+                let locals = bindings.iter().map(|(local, _)| (local.clone(), None)).collect::<Vec<_>>();
+                if !locals.is_empty() {
+                    items.push(ModuleItem::Stmt(var_decl(VarDeclKind::Let, locals)));
+                }
+                let init = member_chain(&["result", "instance", "exports"]);
+                let mut stmts = vec![var_decl(VarDeclKind::Const, vec![(exports.clone(), Some(init))])];
+                for (local, value) in values {
+                    let assign = Expr::Assign(AssignExpr {
+                        span: DUMMY_SP,
+                        op: AssignOp::Assign,
+                        left: PatOrExpr::Pat(Box::new(binding(local))),
+                        right: Box::new(value),
+                    });
+                    stmts.push(expr_stmt(assign));
+                }
+                let callback = arrow_with(&["result"], BlockStmtOrExpr::BlockStmt(BlockStmt { span: DUMMY_SP, stmts }));
+                let expr = call(member(instantiate, "then"), vec![callback]);
+                let ready = ident(&format!("__wasm_ready_{index}"));
+                let mut decl = var_decl(VarDeclKind::Const, vec![(ready, Some(expr))]);
+                if let Stmt::Decl(Decl::Var(var)) = &mut decl {
+                    var.span = span;
+                }
+                items.push(ModuleItem::Stmt(decl));
+            }
+        }
+        items
+    }
+}
+
+impl VisitMut for WasmImport<'_> {
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        // the imports of the same file share one instance, which is created at the first one
+        let mut instances: Vec<Instance> = Vec::new();
+        let mut rewritten = Vec::new();
+        for (index, item) in n.body.iter().enumerate() {
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) = item else { continue };
+            if decl.type_only || !decl.src.value.ends_with(".wasm") {
+                continue;
+            }
+            let specifier = decl.src.value.to_string();
+            let instance = instances.iter_mut().find(|instance| instance.specifier == specifier);
+            let sections = match &instance {
+                Some(instance) => Some(instance.sections.clone()),
+                None => self.sections(decl),
+            };
+            let Some(sections) = sections else { continue };
+            let Some(bindings) = self.bindings(decl, &sections) else { continue };
+            match instance {
+                Some(instance) => instance.bindings.extend(bindings),
+                None => instances.push(Instance { specifier, span: decl.span, sections, bindings }),
+            }
+            rewritten.push(index);
+        }
+
+        if self.syntax == WasmImportSyntax::Promise {
+            let mut references = TopLevelReferences::default();
+            for instance in &instances {
+                references.locals.extend(instance.bindings.iter().map(|(local, _)| local.to_id()));
+            }
+            n.visit_children_with(&mut references);
+            for id in references.found {
+                error(id.span, &format!("`{}` is not instantiated until `__wasm_ready` settles", id.sym));
+            }
+        }
+
+        let count = instances.len();
+        let mut body = Vec::with_capacity(n.body.len());
+        for (index, item) in std::mem::take(&mut n.body).into_iter().enumerate() {
+            if !rewritten.contains(&index) {
+                body.push(item);
+                continue;
+            }
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(decl)) = &item else { unreachable!() };
+            if let Some(i) = instances.iter().position(|instance| instance.specifier == *decl.src.value) {
+                let instance = instances.remove(i);
+                body.extend(self.instantiate(instance));
+                if instances.is_empty() && self.syntax == WasmImportSyntax::Promise {
+                    body.push(self.ready(count));
+                }
+            }
+        }
+        n.body = body;
+    }
+}
+
+impl WasmImport<'_> {
+    /// `export const __wasm_ready = Promise.all([__wasm_ready_0, __wasm_ready_1]);`
+    fn ready(&self, count: usize) -> ModuleItem {
+        let elems = (self.index - count..self.index).map(|index| {
+            Some(ExprOrSpread::from(ident_expr(&format!("__wasm_ready_{index}"))))
+        });
+        let all = call(member_chain(&["Promise", "all"]), vec![Expr::Array(ArrayLit { span: DUMMY_SP, elems: elems.collect() })]);
+        let Stmt::Decl(decl) = var_decl(VarDeclKind::Const, vec![(ident("__wasm_ready"), Some(all))]) else { unreachable!() };
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span: DUMMY_SP, decl }))
+    }
+}
+
+/// The references to the wasm bindings that are evaluated along with the module, the ones
+/// in functions are left to be called after `__wasm_ready` settles.
+#[derive(Default)]
+struct TopLevelReferences {
+    locals: Vec<Id>,
+    found: Vec<Ident>,
+}
+
+impl Visit for TopLevelReferences {
+    fn visit_import_decl(&mut self, _: &ImportDecl) {}
+
+    fn visit_function(&mut self, _: &Function) {}
+
+    fn visit_arrow_expr(&mut self, _: &ArrowExpr) {}
+
+    fn visit_member_prop(&mut self, n: &MemberProp) {
+        if let MemberProp::Computed(prop) = n {
+            prop.visit_with(self);
+        }
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName) {
+        if let PropName::Computed(prop) = n {
+            prop.visit_with(self);
+        }
+    }
+
+    fn visit_ident(&mut self, n: &Ident) {
+        if self.locals.contains(&n.to_id()) {
+            self.found.push(n.clone());
+        }
+    }
+}
@@ -0,0 +1,5 @@
+const React = require('react');
+
+module.exports = function App() {
+  return React.createElement('div');
+};
@@ -0,0 +1,5 @@
+"use strict";
+const React = require('react');
+module.exports = function App() {
+    return React.createElement('div');
+};
@@ -0,0 +1,10 @@
+import React, { useState } from 'react';
+import classnames from 'classnames';
+import moment, * as momentNs from 'moment';
+import { foo } from './foo';
+import './style.css';
+
+export default function App() {
+  const [active] = useState(false);
+  return React.createElement('div', { className: classnames({ active }) }, moment().format(), momentNs, foo);
+}
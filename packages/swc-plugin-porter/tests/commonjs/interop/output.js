@@ -0,0 +1,30 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "default" //# porterMetadata={"__esModule":true}
+, {
+    enumerable: true,
+    get: function() {
+        return App;
+    }
+});
+var _react = /*#__PURE__*/ _interop_require_wildcard(require("react"), true);
+var _classnames = require("classnames");
+var _moment = /*#__PURE__*/ _interop_require_wildcard(require("moment"), true);
+var _foo = require("./foo");
+require("./style.css");
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _porter_interop_default(m) {
+    return m && m.__esModule ? m.default : m;
+}
+function App() {
+    const [active] = (0, _react.useState)(false);
+    return _porter_interop_default(_react.default).createElement('div', {
+        className: _porter_interop_default(_classnames)({
+            active
+        })
+    }, _porter_interop_default(_moment.default)().format(), _moment, _foo.foo);
+}
@@ -0,0 +1,25 @@
+use swc_core::{
+    common::{chain, Mark},
+    ecma::{
+        transforms::{
+            base::{feature::FeatureFlag, resolver},
+            module::{common_js, util::{Config as CommonJsConfig, ImportInterop}},
+            testing::parse_options,
+        },
+        visit::as_folder,
+    },
+};
+use swc_plugin_porter::{porter_transform, Config};
+
+// chained with the commonjs transform porter runs after the plugins, which imports the
+// default as `module.exports` since the interop helper unwraps it, see JsModule._transform
+porter_swc_common::fixture!(fixture, "tests/commonjs/**/input.js", |input, t| {
+    let config: Config = parse_options(input.parent().unwrap());
+    let unresolved_mark = Mark::new();
+    let commonjs = CommonJsConfig { import_interop: Some(ImportInterop::Node), ..Default::default() };
+    chain!(
+        resolver(unresolved_mark, Mark::new(), false),
+        as_folder(porter_transform(input.to_str().unwrap().to_string(), config, t.comments.clone())),
+        common_js(unresolved_mark, commonjs, FeatureFlag::empty(), Some(t.comments.clone())),
+    )
+});
@@ -0,0 +1,7 @@
+const wasm = new URL('./pkg/hello_wasm_bg.wasm', import.meta.url);
+const worker = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' });
+const logo = new URL('./logo.png', import.meta.url).href;
+const remote = new URL('https://example.com/foo.js');
+const cdn = new URL('https://example.com/bar.js', import.meta.url);
+const inline = new URL('data:text/javascript,export default 1', import.meta.url);
+console.log(wasm, worker, logo, remote, cdn, inline);
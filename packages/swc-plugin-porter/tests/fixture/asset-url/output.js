@@ -0,0 +1,9 @@
+const wasm = new URL("./pkg/hello_wasm_bg.4f8b1a2c.wasm", require.meta.url);
+const worker = new Worker(new URL("./worker.9e107d9d.js", require.meta.url), {
+    type: 'module'
+});
+const logo = new URL('./logo.png', require.meta.url).href;
+const remote = new URL('https://example.com/foo.js');
+const cdn = new URL('https://example.com/bar.js', require.meta.url);
+const inline = new URL('data:text/javascript,export default 1', require.meta.url);
+console.log(wasm, worker, logo, remote, cdn, inline); //# porterMetadata={"assets":[{"specifier":"./pkg/hello_wasm_bg.wasm","kind":"url"},{"specifier":"./worker.js","kind":"worker"},{"specifier":"./logo.png","kind":"url"}]}
@@ -0,0 +1,4 @@
+import styles from './button.module.css';
+import { secondary } from './button.module.css';
+
+console.log(styles.button, styles.buton, secondary);
@@ -0,0 +1,3 @@
+import styles from './button.module.css';
+import { secondary } from './button.module.css';
+console.log("button_a1b2c3", styles.buton, secondary);
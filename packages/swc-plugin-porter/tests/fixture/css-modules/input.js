@@ -0,0 +1,10 @@
+import styles from './button.module.css';
+import * as layout from './layout.module.css';
+import { primary, 'icon-large' as iconLarge } from './icon.module.css';
+import theme from './theme.module.css';
+
+export function Button({ size }) {
+  const className = [styles.button, styles['button-large'], primary].join(' ');
+  const icon = { primary, iconLarge };
+  return { className, icon, sidebar: layout.sidebar, size: theme[size] };
+}
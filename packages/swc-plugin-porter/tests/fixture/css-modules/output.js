@@ -0,0 +1,21 @@
+import './button.module.css';
+import './layout.module.css';
+import './icon.module.css';
+import theme from './theme.module.css';
+export function Button({ size }) {
+    const className = [
+        "button_a1b2c3",
+        "button-large_a1b2c3",
+        "primary_d4e5f6 icon_d4e5f6"
+    ].join(' ');
+    const icon = {
+        primary: "primary_d4e5f6 icon_d4e5f6",
+        iconLarge: "icon-large_d4e5f6"
+    };
+    return {
+        className,
+        icon,
+        sidebar: "sidebar_e5f6a7",
+        size: theme[size]
+    };
+}
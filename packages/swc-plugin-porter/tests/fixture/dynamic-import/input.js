@@ -0,0 +1,15 @@
+require.async('./foo');
+require.async('./foo', function(exports) {
+  console.log(exports);
+});
+require.async(['./foo', './bar'], function(foo, bar) {
+  console.log(foo, bar);
+});
+import('./bar');
+
+const lang = navigator.language.split('-')[0];
+import(`../../data/locales/${lang}.json`).then(messages => console.log(messages));
+require.async('../../data/locales/' + lang + '.json', function(messages) {
+  console.log(messages);
+});
+import(lang);
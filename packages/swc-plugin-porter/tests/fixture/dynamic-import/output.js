@@ -0,0 +1,23 @@
+import('./foo');
+import('./foo').then(function(exports) {
+    console.log(exports);
+});
+require.async([
+    './foo',
+    './bar'
+], function(foo, bar) {
+    console.log(foo, bar);
+});
+import('./bar');
+const lang = navigator.language.split('-')[0];
+((map, key)=>Object.prototype.hasOwnProperty.call(map, key) ? map[key]() : Promise.reject(new Error("Cannot find module '" + key + "'")))({
+    "../../data/locales/en.json": ()=>import("../../data/locales/en.json"),
+    "../../data/locales/zh.json": ()=>import("../../data/locales/zh.json")
+}, `../../data/locales/${lang}.json`).then((messages)=>console.log(messages));
+((map, key)=>Object.prototype.hasOwnProperty.call(map, key) ? map[key]() : Promise.reject(new Error("Cannot find module '" + key + "'")))({
+    "../../data/locales/en.json": ()=>import("../../data/locales/en.json"),
+    "../../data/locales/zh.json": ()=>import("../../data/locales/zh.json")
+}, '../../data/locales/' + lang + '.json').then(function(messages) {
+    console.log(messages);
+});
+import(lang);
@@ -0,0 +1,4 @@
+const React = require('react');
+module.exports = function App() {
+    return React.createElement('div');
+};
@@ -0,0 +1,3 @@
+import { foo } from './foo';
+
+export const bar = foo + 1;
@@ -0,0 +1,5 @@
+import { foo } from './foo';
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+export const bar = foo + 1; //# porterMetadata={"__esModule":true}
@@ -0,0 +1,19 @@
+import React, { useState } from 'react';
+import classnames from 'classnames';
+import moment, * as momentNs from 'moment';
+import { foo } from './foo';
+import './style.css';
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _porter_interop_default(m) {
+    return m && m.__esModule ? m.default : m;
+}
+export default function App() {
+    const [active] = useState(false);
+    return _porter_interop_default(React).createElement('div', {
+        className: _porter_interop_default(classnames)({
+            active
+        })
+    }, _porter_interop_default(moment)().format(), momentNs, foo);
+} //# porterMetadata={"__esModule":true}
@@ -0,0 +1,7 @@
+require.async('./foo', function(exports) {
+  console.log(exports);
+});
+import('./bar').then(exports => console.log(exports));
+
+const lang = navigator.language.split('-')[0];
+import(`../../data/locales/${lang}.json`);
@@ -0,0 +1,9 @@
+require.async('./foo', function(exports) {
+    console.log(exports);
+});
+new Promise((resolve)=>require.async('./bar', resolve)).then((exports)=>console.log(exports));
+const lang = navigator.language.split('-')[0];
+((map, key)=>Object.prototype.hasOwnProperty.call(map, key) ? map[key]() : Promise.reject(new Error("Cannot find module '" + key + "'")))({
+    "../../data/locales/en.json": ()=>new Promise((resolve)=>require.async("../../data/locales/en.json", resolve)),
+    "../../data/locales/zh.json": ()=>new Promise((resolve)=>require.async("../../data/locales/zh.json", resolve))
+}, `../../data/locales/${lang}.json`);
@@ -0,0 +1,14 @@
+import fs, { readFileSync } from 'fs';
+import * as path from 'path';
+import 'buffer';
+export * from 'stream';
+export { inspect, format as formatUtil } from 'util';
+export { default as events, EventEmitter as default } from 'events';
+export * as url from 'url';
+
+const os = require('os');
+import('crypto').then(crypto => console.log(crypto));
+require.async('crypto', function(crypto) {
+  console.log(crypto);
+});
+console.log(fs, readFileSync, path, os);
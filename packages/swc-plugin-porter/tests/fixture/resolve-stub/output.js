@@ -0,0 +1,13 @@
+const fs = {}, readFileSync = undefined;
+const path = {};
+export const inspect = undefined, formatUtil = undefined;
+export const events = {};
+const __porter_stub_0 = undefined;
+export { __porter_stub_0 as default };
+export const url = {};
+const os = {};
+Promise.resolve({}).then((crypto)=>console.log(crypto));
+require.async('crypto', function(crypto) {
+    console.log(crypto);
+});
+console.log(fs, readFileSync, path, os);
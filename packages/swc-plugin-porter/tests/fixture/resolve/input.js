@@ -0,0 +1,12 @@
+import React from 'react';
+import { foo } from './foo';
+import './style.css';
+export * from './bar';
+export { baz as qux } from './baz';
+
+const lodash = require('lodash');
+require.async('./lazy', function(exports) {
+  console.log(exports);
+});
+import('./lazy').then(exports => console.log(exports));
+console.log(React, foo, lodash, require('./unknown'));
@@ -0,0 +1,11 @@
+import React from "react/18.2.0/index.js";
+import { foo } from "./foo.js";
+import "./style.css";
+export * from "./bar/index.js";
+export { baz as qux } from "./baz.ts";
+const lodash = require("lodash/4.17.21/lodash.js");
+require.async("./lazy.jsx", function(exports) {
+    console.log(exports);
+});
+import("./lazy.jsx").then((exports)=>console.log(exports));
+console.log(React, foo, lodash, require('./unknown'));
@@ -0,0 +1,3 @@
+import init, { hello } from '../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm';
+import { greet, goodbye } from '../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm';
+import { missing } from './missing.wasm';
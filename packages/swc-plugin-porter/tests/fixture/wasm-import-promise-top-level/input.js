@@ -0,0 +1,5 @@
+import { greet, memory } from '../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm';
+
+const { buffer } = memory;
+greet('porter');
+export const greetLater = () => greet('later');
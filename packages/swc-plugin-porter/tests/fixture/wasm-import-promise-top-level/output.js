@@ -0,0 +1,15 @@
+import * as __wasm_imports_0_0 from "../../../../../examples/hello-wasm/pkg/bundler/index_bg.js";
+let greet, memory;
+const __wasm_ready_0 = WebAssembly.instantiateStreaming(fetch(new URL("../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm", require.meta.url)), {
+    "./index_bg.js": __wasm_imports_0_0
+}).then((result)=>{
+    const __wasm_0 = result.instance.exports;
+    greet = __wasm_0.greet;
+    memory = __wasm_0.memory;
+});
+export const __wasm_ready = Promise.all([
+    __wasm_ready_0
+]);
+const { buffer } = memory;
+greet('porter');
+export const greetLater = ()=>greet('later'); //# porterMetadata={"assets":[{"specifier":"../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm","kind":"url"}]}
@@ -0,0 +1,9 @@
+import { greet, memory as mem } from '../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm';
+import * as wasm from '../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm';
+
+// the bindings are assigned once __wasm_ready settles
+export async function main() {
+  await __wasm_ready;
+  greet('porter');
+  console.log(mem.buffer, wasm.__wbindgen_malloc);
+}
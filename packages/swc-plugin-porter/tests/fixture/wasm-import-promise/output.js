@@ -0,0 +1,19 @@
+import * as __wasm_imports_0_0 from "../../../../../examples/hello-wasm/pkg/bundler/index_bg.js";
+let greet, mem, wasm;
+const __wasm_ready_0 = WebAssembly.instantiateStreaming(fetch(new URL("../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm", require.meta.url)), {
+    "./index_bg.js": __wasm_imports_0_0
+}).then((result)=>{
+    const __wasm_0 = result.instance.exports;
+    greet = __wasm_0.greet;
+    mem = __wasm_0.memory;
+    wasm = __wasm_0;
+});
+export const __wasm_ready = Promise.all([
+    __wasm_ready_0
+]);
+// the bindings are assigned once __wasm_ready settles
+export async function main() {
+    await __wasm_ready;
+    greet('porter');
+    console.log(mem.buffer, wasm.__wbindgen_malloc);
+} //# porterMetadata={"assets":[{"specifier":"../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm","kind":"url"}]}
@@ -0,0 +1,5 @@
+import { greet, memory as mem } from '../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm';
+import * as wasm from '../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm';
+
+greet('porter');
+console.log(mem.buffer, wasm.__wbindgen_malloc);
@@ -0,0 +1,9 @@
+import * as __wasm_imports_0_0 from "../../../../../examples/hello-wasm/pkg/bundler/index_bg.js";
+const __wasm_0 = (await WebAssembly.instantiateStreaming(fetch(new URL("/hello-wasm/index_bg.4f8b1a2c.wasm", require.meta.url)), {
+    "./index_bg.js": __wasm_imports_0_0
+})).instance.exports;
+const greet = __wasm_0.greet;
+const mem = __wasm_0.memory;
+const wasm = __wasm_0;
+greet('porter');
+console.log(mem.buffer, wasm.__wbindgen_malloc); //# porterMetadata={"assets":[{"specifier":"../../../../../examples/hello-wasm/pkg/bundler/index_bg.wasm","kind":"url"}]}
@@ -0,0 +1,10 @@
+import MyWorker from './worker.js?worker';
+import MySharedWorker from './shared.js?sharedworker';
+
+const worker = new MyWorker({ type: 'module' });
+const shared = new SharedWorker(new URL('./shared.js', import.meta.url), { name: 'shared' });
+const other = new MySharedWorker();
+const icon = new Worker(new URL('./worker.js', import.meta.url), {
+    name: String(new URL('./icon.png', import.meta.url)),
+});
+console.log(worker, shared, other, icon);
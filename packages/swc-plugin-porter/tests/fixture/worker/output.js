@@ -0,0 +1,17 @@
+function MyWorker(options) {
+    return new Worker(new URL("/worker.9e107d9d.js", require.meta.url), options);
+}
+function MySharedWorker(options) {
+    return new SharedWorker(new URL("/shared.1b2c3d4e.js", require.meta.url), options);
+}
+const worker = new MyWorker({
+    type: 'module'
+});
+const shared = new SharedWorker(new URL("/shared.1b2c3d4e.js", require.meta.url), {
+    name: 'shared'
+});
+const other = new MySharedWorker();
+const icon = new Worker(new URL("/worker.9e107d9d.js", require.meta.url), {
+    name: String(new URL('./icon.png', require.meta.url))
+});
+console.log(worker, shared, other, icon); //# porterMetadata={"assets":[{"specifier":"./worker.js","kind":"worker"},{"specifier":"./shared.js","kind":"sharedWorker"},{"specifier":"./icon.png","kind":"url"}]}
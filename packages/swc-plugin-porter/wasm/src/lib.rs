@@ -0,0 +1,15 @@
+use porter::porter_transform;
+use porter_swc_common::config::{plugin_config, plugin_filepath};
+use swc_core::{
+    ecma::{ast::Program, visit::{as_folder, FoldWith}},
+    plugin::{plugin_transform, proxies::{PluginCommentsProxy, TransformPluginProgramMetadata}},
+};
+
+/// Runs the transforms of porter on the module, the config is documented in
+/// [porter::Config].
+#[plugin_transform]
+pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
+    let filepath = plugin_filepath(&metadata);
+    let config = plugin_config(&metadata, "swc-plugin-porter");
+    program.fold_with(&mut as_folder(porter_transform(filepath, config, PluginCommentsProxy)))
+}